@@ -0,0 +1,171 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+// Storage keys
+const ADMIN: Symbol = symbol_short!("admin");
+const TOKEN: Symbol = symbol_short!("token");
+const ROOT: Symbol = symbol_short!("root");
+const EXPIRY: Symbol = symbol_short!("expiry");
+
+// Number of claim flags per bitmap word
+const BITMAP_WORD_BITS: u32 = 128;
+
+// Domain separation prefixes, so a parent node can never be passed off as a leaf
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+// Persistent storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    ClaimedWord(u32),
+}
+
+// Merkle tree leaf, hashed as sha256 of 0x00 followed by its XDR encoding
+// Parent nodes are sha256 of 0x01 followed by the two child hashes, smaller hash first
+#[contracttype]
+#[derive(Clone)]
+pub struct AirdropLeaf {
+    pub index: u32,
+    pub account: Address,
+    pub amount: i128,
+}
+
+// Distributes tokens held by this contract to recipients in the Merkle tree
+#[contract]
+pub struct AirdropContract;
+
+#[contractimpl]
+impl AirdropContract {
+
+    /// Initialize distributor with Merkle root of (index, address, amount) leaves
+    /// Unclaimed tokens can be swept by admin after `expiry`
+    pub fn initialize(env: Env, admin: Address, token: Address, merkle_root: BytesN<32>, expiry: u64) {
+        admin.require_auth();
+
+        if env.storage().instance().has(&ADMIN) {
+            panic!("Airdrop already initialized");
+        }
+
+        if expiry <= env.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        env.storage().instance().set(&ADMIN, &admin);
+        env.storage().instance().set(&TOKEN, &token);
+        env.storage().instance().set(&ROOT, &merkle_root);
+        env.storage().instance().set(&EXPIRY, &expiry);
+    }
+
+    /// Claim airdrop for leaf `index` with Merkle proof (bisa disubmit siapa saja)
+    /// Tokens always go to `account` from the leaf
+    pub fn claim(env: Env, index: u32, account: Address, amount: i128, proof: Vec<BytesN<32>>) {
+        let expiry: u64 = env.storage().instance().get(&EXPIRY).unwrap();
+        if env.ledger().timestamp() > expiry {
+            panic!("Airdrop expired");
+        }
+
+        if Self::is_claimed(env.clone(), index) {
+            panic!("Already claimed");
+        }
+
+        let leaf = AirdropLeaf {
+            index,
+            account: account.clone(),
+            amount,
+        };
+        let root: BytesN<32> = env.storage().instance().get(&ROOT).unwrap();
+        if Self::compute_root(&env, &leaf, &proof) != root {
+            panic!("Invalid proof");
+        }
+
+        Self::set_claimed(&env, index);
+
+        let token_address: Address = env.storage().instance().get(&TOKEN).unwrap();
+        token::Client::new(&env, &token_address).transfer(&env.current_contract_address(), &account, &amount);
+    }
+
+    /// Check apakah leaf `index` sudah di-claim
+    pub fn is_claimed(env: Env, index: u32) -> bool {
+        let word: u128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimedWord(index / BITMAP_WORD_BITS))
+            .unwrap_or(0);
+        word & (1u128 << (index % BITMAP_WORD_BITS)) != 0
+    }
+
+    /// Send all unclaimed tokens to admin after expiry - Admin only
+    /// Returns amount swept
+    pub fn sweep(env: Env) -> i128 {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        let expiry: u64 = env.storage().instance().get(&EXPIRY).unwrap();
+        if env.ledger().timestamp() <= expiry {
+            panic!("Airdrop not expired yet");
+        }
+
+        let token_address: Address = env.storage().instance().get(&TOKEN).unwrap();
+        let token = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let amount = token.balance(&contract_address);
+        if amount > 0 {
+            token.transfer(&contract_address, &admin, &amount);
+        }
+
+        amount
+    }
+
+    /// Get admin
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN).unwrap()
+    }
+
+    /// Get Merkle root
+    pub fn get_merkle_root(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&ROOT).unwrap()
+    }
+
+    /// Get claim expiry timestamp
+    pub fn get_expiry(env: Env) -> u64 {
+        env.storage().instance().get(&EXPIRY).unwrap()
+    }
+}
+
+impl AirdropContract {
+
+    // Hash leaf, then fold proof hashes up to the root
+    fn compute_root(env: &Env, leaf: &AirdropLeaf, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut hash = Self::hash_leaf(env, leaf);
+        for sibling in proof.iter() {
+            hash = Self::hash_pair(env, &hash, &sibling);
+        }
+        hash
+    }
+
+    // sha256 of the leaf prefix and the leaf XDR
+    fn hash_leaf(env: &Env, leaf: &AirdropLeaf) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &[LEAF_PREFIX]);
+        data.append(&leaf.clone().to_xdr(env));
+        env.crypto().sha256(&data).into()
+    }
+
+    // sha256 of the node prefix and the two hashes, smaller first (proof needs no left/right flags)
+    fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(env, &[NODE_PREFIX]);
+        data.extend_from_array(&first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).into()
+    }
+
+    fn set_claimed(env: &Env, index: u32) {
+        let key = DataKey::ClaimedWord(index / BITMAP_WORD_BITS);
+        let word: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(word | (1u128 << (index % BITMAP_WORD_BITS))));
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,194 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address, Env, String};
+
+// Helper function to hash a leaf the way the off-chain tree builder does: 0x00 || leaf XDR
+fn leaf_hash(env: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    let leaf = AirdropLeaf {
+        index,
+        account: account.clone(),
+        amount,
+    };
+    let mut data = Bytes::from_array(env, &[0x00]);
+    data.append(&leaf.to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+// Helper function to hash two nodes the way the off-chain tree builder does: 0x01 || smaller || larger
+fn node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &[0x01]);
+    data.extend_from_array(&first.to_array());
+    data.extend_from_array(&second.to_array());
+    env.crypto().sha256(&data).into()
+}
+
+// Four donors: tree of two levels
+//        root
+//      /      \
+//    h01      h23
+//   /  \     /   \
+//  l0  l1   l2   l3
+struct Tree {
+    recipients: [Address; 4],
+    amounts: [i128; 4],
+    leaves: [BytesN<32>; 4],
+    h01: BytesN<32>,
+    h23: BytesN<32>,
+    root: BytesN<32>,
+}
+
+fn build_tree(env: &Env) -> Tree {
+    let recipients = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let amounts = [100_000, 200_000, 300_000, 400_000];
+    let leaves = [0, 1, 2, 3].map(|i| leaf_hash(env, i as u32, &recipients[i], amounts[i]));
+    let h01 = node_hash(env, &leaves[0], &leaves[1]);
+    let h23 = node_hash(env, &leaves[2], &leaves[3]);
+    let root = node_hash(env, &h01, &h23);
+    Tree { recipients, amounts, leaves, h01, h23, root }
+}
+
+// Helper function to deploy a distributor funded with 1_000_000 of a 10_000_000 donor token
+// supply issued to admin, expiring in 1000s
+fn setup_airdrop<'a>(env: &Env, admin: &Address, tree: &Tree) -> (AirdropContractClient<'a>, token::Client<'a>) {
+    let token_address = env.register(::token::TokenContract, ());
+    let params = ::token::TokenParams {
+        name: String::from_str(env, "Donor Token"),
+        symbol: String::from_str(env, "DNR"),
+        decimals: 7,
+        clawback_enabled: false,
+    };
+    ::token::TokenContractClient::new(env, &token_address).initialize(admin, &params, &10_000_000i128, &None, &None);
+    let token = token::Client::new(env, &token_address);
+
+    let contract_id = env.register(AirdropContract, ());
+    let client = AirdropContractClient::new(env, &contract_id);
+
+    let expiry = env.ledger().timestamp() + 1000;
+    client.initialize(admin, &token_address, &tree.root, &expiry);
+    token.transfer(admin, &contract_id, &1_000_000);
+
+    (client, token)
+}
+
+#[test]
+fn test_claim_with_proof() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let tree = build_tree(&env);
+    let (client, token) = setup_airdrop(&env, &admin, &tree);
+
+    // Leaf 2 proof: sibling l3, then h01
+    let proof = vec![&env, tree.leaves[3].clone(), tree.h01.clone()];
+    assert!(!client.is_claimed(&2));
+
+    // Relayer submits, tokens go to the leaf account
+    env.set_auths(&[]);
+    client.claim(&2, &tree.recipients[2], &tree.amounts[2], &proof);
+
+    assert!(client.is_claimed(&2));
+    assert!(!client.is_claimed(&3));
+    assert_eq!(token.balance(&tree.recipients[2]), 300_000);
+
+    // Leaf 1 proof: sibling l0, then h23
+    let proof = vec![&env, tree.leaves[0].clone(), tree.h23.clone()];
+    client.claim(&1, &tree.recipients[1], &tree.amounts[1], &proof);
+    assert_eq!(token.balance(&client.address), 500_000);
+}
+
+#[test]
+#[should_panic(expected = "Already claimed")]
+fn test_claim_twice() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let tree = build_tree(&env);
+    let (client, _) = setup_airdrop(&env, &admin, &tree);
+
+    let proof = vec![&env, tree.leaves[1].clone(), tree.h23.clone()];
+    client.claim(&0, &tree.recipients[0], &tree.amounts[0], &proof);
+    client.claim(&0, &tree.recipients[0], &tree.amounts[0], &proof);
+}
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn test_claim_wrong_amount() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let tree = build_tree(&env);
+    let (client, _) = setup_airdrop(&env, &admin, &tree);
+
+    let proof = vec![&env, tree.leaves[1].clone(), tree.h23.clone()];
+    client.claim(&0, &tree.recipients[0], &(tree.amounts[0] * 10), &proof);
+}
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn test_claim_tree_without_domain_prefixes() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    // Same leaves hashed without the leaf/node prefixes
+    let mut tree = build_tree(&env);
+    let raw_leaves = [0, 1, 2, 3].map(|i| {
+        let leaf = AirdropLeaf {
+            index: i as u32,
+            account: tree.recipients[i].clone(),
+            amount: tree.amounts[i],
+        };
+        let hash: BytesN<32> = env.crypto().sha256(&leaf.to_xdr(&env)).into();
+        hash
+    });
+    let raw_pair = |a: &BytesN<32>, b: &BytesN<32>| -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(&env, &first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).into()
+    };
+    let raw_h23 = raw_pair(&raw_leaves[2], &raw_leaves[3]);
+    tree.root = raw_pair(&raw_pair(&raw_leaves[0], &raw_leaves[1]), &raw_h23);
+    let (client, _) = setup_airdrop(&env, &admin, &tree);
+
+    let proof = vec![&env, raw_leaves[1].clone(), raw_h23];
+    client.claim(&0, &tree.recipients[0], &tree.amounts[0], &proof);
+}
+
+#[test]
+fn test_sweep_after_expiry() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let tree = build_tree(&env);
+    let (client, token) = setup_airdrop(&env, &admin, &tree);
+
+    let proof = vec![&env, tree.leaves[2].clone(), tree.h01.clone()];
+    client.claim(&3, &tree.recipients[3], &tree.amounts[3], &proof);
+
+    // Sweep not allowed while claims are open
+    assert!(client.try_sweep().is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1001;
+    });
+
+    // Claims closed after expiry
+    let proof = vec![&env, tree.leaves[3].clone(), tree.h01.clone()];
+    assert!(client.try_claim(&2, &tree.recipients[2], &tree.amounts[2], &proof).is_err());
+
+    assert_eq!(client.sweep(), 600_000);
+    assert_eq!(token.balance(&admin), 9_600_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
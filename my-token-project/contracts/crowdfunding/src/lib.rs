@@ -1,11 +1,12 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contract, contractclient, contracterror, contractevent, contractimpl, contracttype, panic_with_error, symbol_short, token, vec, xdr::ToXdr, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 // Storage keys untuk contract data
 // Kita pakai symbol_short! untuk efisiensi (max 9 karakter)
 const CAMPAIGN_GOAL: Symbol = symbol_short!("goal");
 const CAMPAIGN_DEADLINE: Symbol = symbol_short!("deadline");
 const TOTAL_RAISED: Symbol = symbol_short!("raised");
+// Legacy (schema v1) instance map of donations, see `migrate`
 const DONATIONS: Symbol = symbol_short!("donations");
 const CAMPAIGN_OWNER: Symbol = symbol_short!("owner");
 const XLM_TOKEN_ADDRESS: Symbol = symbol_short!("xlm_addr");
@@ -18,16 +19,235 @@ const CAMPAIGN_CATEGORY: Symbol = symbol_short!("category");
 const DONATION_HISTORY: Symbol = symbol_short!("history");
 const WITHDRAWN: Symbol = symbol_short!("withdrawn");
 const CREATED_AT: Symbol = symbol_short!("created");
+const PAYOUT_SPLITS: Symbol = symbol_short!("splits");
+const CAMPAIGN_CONFIG: Symbol = symbol_short!("config");
+
+// Vesting stream state
+const CLAIMED: Symbol = symbol_short!("claimed");
+const HALT_VOTES: Symbol = symbol_short!("halt_vote");
+const HALTED: Symbol = symbol_short!("halted");
+const VESTED_AT_HALT: Symbol = symbol_short!("vest_halt");
+
+// Referrer leaderboard
+const TOP_REFERRERS: Symbol = symbol_short!("top_refs");
+
+// Guardian emergency controls
+const PAUSED: Symbol = symbol_short!("paused");
+const REFUND_ONLY: Symbol = symbol_short!("refund_on");
+
+// Dispute window before owner withdrawal
+const DISPUTE_VOTES: Symbol = symbol_short!("disp_vote");
+const DISPUTED: Symbol = symbol_short!("disputed");
+const DISPUTE_RESOLVED: Symbol = symbol_short!("disp_res");
+
+// Total seconds the deadline has been auto-extended (anti-sniping)
+const DEADLINE_EXTENDED: Symbol = symbol_short!("extended");
+
+// Storage schema version (v1 = before versioning)
+const SCHEMA_VERSION: Symbol = symbol_short!("schema_v");
+const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+// Aggregate donor statistics (maintained incrementally)
+const DONOR_COUNT: Symbol = symbol_short!("donor_cnt");
+// Sum of non-refunded donations (pro-rata refunds keep total raised unchanged)
+const DONATED: Symbol = symbol_short!("donated");
+const TOP_DONATIONS: Symbol = symbol_short!("top_dons");
+const TOP_DONORS: Symbol = symbol_short!("top_donor");
+
+// Max number of donors kept in the top donors leaderboard
+const TOP_DONORS_LIMIT: u32 = 10;
+
+// Max number of donors kept as largest donation candidates
+const TOP_DONATIONS_LIMIT: u32 = 10;
+
+// Max number of referrers kept in the top referrers leaderboard
+const TOP_REFERRERS_LIMIT: u32 = 10;
+
+// Basis points denominator (10000 bps = 100%)
+const BPS_DENOMINATOR: u32 = 10_000;
+
+// Persistent storage keys (one entry per donor)
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Donation(Address),
+    HaltVoter(Address),
+    DisputeFlagger(Address),
+    Subscription(Address),
+    Referral(Address),
+    PledgeKey(Address),
+    PledgeNonce(Address, u64),
+}
+
+// Off-chain donor pledge, donor signs its XDR encoding with their pledge key
+#[contracttype]
+#[derive(Clone)]
+pub struct Pledge {
+    pub campaign: Address,
+    pub donor: Address,
+    pub amount: i128,
+    pub expiry: u64,
+    pub nonce: u64,
+}
+
+// Donation record as stored by schema v1 (no sequence id)
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyDonationRecord {
+    pub donor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
 
 // Donation record for history tracking
+// id = sequence number in history, stable cursor for paging
 #[contracttype]
 #[derive(Clone)]
 pub struct DonationRecord {
+    pub id: u64,
     pub donor: Address,
     pub amount: i128,
     pub timestamp: u64,
 }
 
+// Typed contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+}
+
+// Recurring donation, collected once per period via token allowance
+#[contracttype]
+#[derive(Clone)]
+pub struct Subscription {
+    pub amount: i128,
+    pub period: u64,
+    pub next_due: u64,
+}
+
+// Referrer leaderboard entry
+#[contracttype]
+#[derive(Clone)]
+pub struct ReferralEntry {
+    pub referrer: Address,
+    pub total: i128,
+}
+
+// Donor leaderboard entry (cumulative donation per donor)
+#[contracttype]
+#[derive(Clone)]
+pub struct DonorEntry {
+    pub donor: Address,
+    pub total: i128,
+}
+
+// Payout recipient and its share of the raised funds (in basis points)
+#[contracttype]
+#[derive(Clone)]
+pub struct PayoutSplit {
+    pub recipient: Address,
+    pub bps: u32,
+}
+
+// Core campaign parameters passed to initialize
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignParams {
+    pub title: String,
+    pub description: String,
+    pub goal: i128,
+    pub deadline: u64,
+    pub category: Symbol,
+    pub xlm_token: Address,
+}
+
+// Optional campaign settings
+// vesting_duration = 0 berarti withdraw langsung semua (tanpa vesting)
+// receipt = donation receipt contract yang di-mint saat donate
+// guardian = platform guardian yang bisa pause / force refund-only
+// dispute_window = detik setelah deadline dimana donors bisa flag campaign (0 = disabled)
+// dispute_threshold_bps = share of total raised needed to freeze withdrawal
+// arbiter = resolves disputes toward payout or refund
+// start_time = scheduled launch, donate ditolak sebelum waktu ini (None = langsung live)
+// extension_window = donasi dalam N detik terakhir sebelum deadline memperpanjang deadline (0 = disabled)
+// extension_duration = detik yang ditambahkan ke deadline per late donation
+// max_extension = batas total perpanjangan deadline
+// router = AMM router untuk donate_with_swap (None = disabled)
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignConfig {
+    pub vesting_duration: u64,
+    pub receipt: Option<Address>,
+    pub guardian: Option<Address>,
+    pub dispute_window: u64,
+    pub dispute_threshold_bps: u32,
+    pub arbiter: Option<Address>,
+    pub start_time: Option<u64>,
+    pub extension_window: u64,
+    pub extension_duration: u64,
+    pub max_extension: u64,
+    pub router: Option<Address>,
+}
+
+// Campaign lifecycle status
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CampaignStatus {
+    Upcoming,
+    Active,
+    Ended,
+}
+
+// Guardian events
+#[contractevent]
+#[derive(Clone)]
+pub struct Paused {
+    #[topic]
+    pub guardian: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct Unpaused {
+    #[topic]
+    pub guardian: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct RefundOnly {
+    #[topic]
+    pub guardian: Address,
+}
+
+// Dispute events
+#[contractevent]
+#[derive(Clone)]
+pub struct DisputeFlagged {
+    #[topic]
+    pub donor: Address,
+    pub votes: i128,
+    pub disputed: bool,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct DisputeResolved {
+    #[topic]
+    pub arbiter: Address,
+    pub payout: bool,
+}
+
+// Anti-sniping event
+#[contractevent]
+#[derive(Clone)]
+pub struct DeadlineExtended {
+    pub new_deadline: u64,
+    pub total_extension: u64,
+}
+
 // Campaign metadata
 #[contracttype]
 #[derive(Clone)]
@@ -39,6 +259,29 @@ pub struct CampaignMetadata {
     pub goal: i128,
     pub deadline: u64,
     pub created_at: u64,
+    pub start_time: Option<u64>,
+}
+
+// Interface of the donation receipt contract (contracts/receipt)
+#[contractclient(name = "ReceiptClient")]
+pub trait ReceiptInterface {
+    fn mint(env: Env, campaign: Address, donor: Address, amount: i128) -> u64;
+    fn burn(env: Env, campaign: Address, donor: Address);
+}
+
+// Interface of an AMM-style swap router
+// Pulls amount_in of token_in from `from`, sends at least min_out of token_out to `to`
+#[contractclient(name = "RouterClient")]
+pub trait RouterInterface {
+    fn swap_exact_in(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128;
 }
 
 // Contract struct
@@ -54,101 +297,890 @@ pub struct CrowdfundingContract;
 impl CrowdfundingContract {
 
     /// Initialize campaign with metadata
+    /// `splits` must sum to 10000 bps; empty means 100% to owner
     pub fn initialize(
         env: Env,
         owner: Address,
-        title: String,
-        description: String,
-        goal: i128,
-        deadline: u64,
-        category: Symbol,
-        xlm_token: Address,
+        params: CampaignParams,
+        splits: Vec<PayoutSplit>,
+        config: CampaignConfig,
     ) {
         // Verify owner authorization
         owner.require_auth();
 
+        let CampaignParams {
+            title,
+            description,
+            goal,
+            deadline,
+            category,
+            xlm_token,
+        } = params;
+
         // Validate inputs
         if goal < 100_000_000 {
             panic!("Goal must be at least 10 XLM");
         }
 
-        if deadline <= env.ledger().timestamp() {
-            panic!("Deadline must be in the future");
+        if deadline <= env.ledger().timestamp() {
+            panic!("Deadline must be in the future");
+        }
+
+        // Validate payout splits (default: everything goes to owner)
+        let splits = if splits.is_empty() {
+            vec![
+                &env,
+                PayoutSplit {
+                    recipient: owner.clone(),
+                    bps: BPS_DENOMINATOR,
+                },
+            ]
+        } else {
+            splits
+        };
+
+        let mut total_bps: u32 = 0;
+        for split in splits.iter() {
+            if split.bps == 0 {
+                panic!("Split share must be greater than 0");
+            }
+            total_bps = total_bps.saturating_add(split.bps);
+        }
+
+        if total_bps != BPS_DENOMINATOR {
+            panic!("Splits must sum to 10000 bps");
+        }
+
+        if let Some(start_time) = config.start_time {
+            if start_time >= deadline {
+                panic!("Start time must be before deadline");
+            }
+        }
+
+        // Validate anti-sniping settings
+        if config.extension_window > 0 && (config.extension_duration == 0 || config.max_extension == 0) {
+            panic!("Extension window requires extension duration and max extension");
+        }
+
+        // Validate dispute settings
+        if config.dispute_window > 0 {
+            if config.arbiter.is_none() {
+                panic!("Dispute window requires an arbiter");
+            }
+            if config.dispute_threshold_bps == 0 || config.dispute_threshold_bps > BPS_DENOMINATOR {
+                panic!("Dispute threshold must be between 1 and 10000 bps");
+            }
+        }
+
+        // Store campaign metadata
+        env.storage().instance().set(&CAMPAIGN_TITLE, &title);
+        env.storage().instance().set(&CAMPAIGN_DESC, &description);
+        env.storage().instance().set(&CAMPAIGN_CATEGORY, &category);
+        env.storage().instance().set(&CAMPAIGN_OWNER, &owner);
+        env.storage().instance().set(&CAMPAIGN_GOAL, &goal);
+        env.storage().instance().set(&CAMPAIGN_DEADLINE, &deadline);
+        env.storage().instance().set(&CREATED_AT, &env.ledger().timestamp());
+
+        // Initialize campaign state
+        env.storage().instance().set(&TOTAL_RAISED, &0i128);
+        env.storage().instance().set(&DONATED, &0i128);
+        env.storage().instance().set(&XLM_TOKEN_ADDRESS, &xlm_token);
+        env.storage().instance().set(&IS_ALREADY_INIT, &true);
+        env.storage().instance().set(&WITHDRAWN, &false);
+        env.storage().instance().set(&PAYOUT_SPLITS, &splits);
+        env.storage().instance().set(&CAMPAIGN_CONFIG, &config);
+        env.storage().instance().set(&CLAIMED, &0i128);
+        env.storage().instance().set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+
+        // Initialize empty history (donations per donor live in persistent storage)
+        let history: Vec<DonationRecord> = Vec::new(&env);
+        env.storage().instance().set(&DONATION_HISTORY, &history);
+    }
+
+    /// Donate to campaign
+    /// Optional `referrer` gets credited for bringing the donor
+    pub fn donate(env: Env, donor: Address, amount: i128, referrer: Option<Address>) {
+        // Verify donor authorization
+        donor.require_auth();
+
+        if referrer == Some(donor.clone()) {
+            panic!("Cannot refer yourself");
+        }
+
+        // Check campaign still accepting donations and amount valid
+        Self::check_can_donate(&env, amount);
+
+        // Get XLM token contract and contract address
+        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+        let xlm_token = token::Client::new(&env, &xlm_token_address);
+        let contract_address = env.current_contract_address();
+
+        // Transfer XLM from donor to contract
+        xlm_token.transfer(&donor, &contract_address, &amount);
+
+        Self::record_donation(&env, &donor, amount);
+
+        if let Some(referrer) = referrer {
+            Self::credit_referrer(&env, referrer, amount);
+        }
+    }
+
+    /// Donate with any token: swap `amount_in` of `token_in` into the campaign token
+    /// via the configured router, reverting if output < `min_out`
+    /// Donor is credited with the actual swap output
+    /// Returns amount credited
+    pub fn donate_with_swap(
+        env: Env,
+        donor: Address,
+        token_in: Address,
+        amount_in: i128,
+        min_out: i128,
+        referrer: Option<Address>,
+    ) -> i128 {
+        donor.require_auth();
+
+        if referrer == Some(donor.clone()) {
+            panic!("Cannot refer yourself");
+        }
+
+        let router = match Self::get_config(env.clone()).router {
+            Some(router) => router,
+            None => panic!("Swap router not configured"),
+        };
+
+        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+        if token_in == xlm_token_address {
+            panic!("Use donate for the campaign token");
+        }
+
+        if amount_in <= 0 {
+            panic!("Amount must be greater than 0");
+        }
+
+        // min_out is the lowest amount that can be credited, validate it as a donation
+        Self::check_can_donate(&env, min_out);
+
+        // Swap straight into this contract, measure what actually arrived
+        let xlm_token = token::Client::new(&env, &xlm_token_address);
+        let contract_address = env.current_contract_address();
+        let balance_before = xlm_token.balance(&contract_address);
+
+        RouterClient::new(&env, &router).swap_exact_in(
+            &donor,
+            &token_in,
+            &xlm_token_address,
+            &amount_in,
+            &min_out,
+            &contract_address,
+        );
+
+        let amount = Self::checked_sub(&env, xlm_token.balance(&contract_address), balance_before);
+        if amount < min_out {
+            panic!("Swap output below minimum");
+        }
+
+        Self::record_donation(&env, &donor, amount);
+
+        if let Some(referrer) = referrer {
+            Self::credit_referrer(&env, referrer, amount);
+        }
+
+        amount
+    }
+
+    /// Register ed25519 public key used to sign donor's off-chain pledges
+    pub fn set_pledge_key(env: Env, donor: Address, public_key: BytesN<32>) {
+        donor.require_auth();
+
+        env.storage().persistent().set(&DataKey::PledgeKey(donor), &public_key);
+    }
+
+    /// Get donor's registered pledge key
+    pub fn get_pledge_key(env: Env, donor: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::PledgeKey(donor))
+    }
+
+    /// Check apakah pledge nonce donor sudah dipakai
+    pub fn is_pledge_nonce_used(env: Env, donor: Address, nonce: u64) -> bool {
+        env.storage().persistent().has(&DataKey::PledgeNonce(donor, nonce))
+    }
+
+    /// Redeem a donor-signed pledge (submitted by any relayer, no donor auth needed)
+    /// Donor must approve an allowance for this contract on the XLM token
+    pub fn redeem_pledge(env: Env, pledge: Pledge, signature: BytesN<64>) {
+        if pledge.campaign != env.current_contract_address() {
+            panic!("Pledge is for another campaign");
+        }
+
+        if env.ledger().timestamp() > pledge.expiry {
+            panic!("Pledge expired");
+        }
+
+        let nonce_key = DataKey::PledgeNonce(pledge.donor.clone(), pledge.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            panic!("Pledge nonce already used");
+        }
+
+        let public_key = match Self::get_pledge_key(env.clone(), pledge.donor.clone()) {
+            Some(public_key) => public_key,
+            None => panic!("Pledge key not registered"),
+        };
+
+        // Panics if signature is invalid
+        env.crypto().ed25519_verify(&public_key, &pledge.clone().to_xdr(&env), &signature);
+
+        Self::check_can_donate(&env, pledge.amount);
+
+        env.storage().persistent().set(&nonce_key, &true);
+
+        // Pull pledged amount using donor's allowance
+        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+        let xlm_token = token::Client::new(&env, &xlm_token_address);
+        let contract_address = env.current_contract_address();
+        xlm_token.transfer_from(&contract_address, &pledge.donor, &contract_address, &pledge.amount);
+
+        Self::record_donation(&env, &pledge.donor, pledge.amount);
+    }
+
+    // Add donated amount to referrer's total and update the leaderboard
+    fn credit_referrer(env: &Env, referrer: Address, amount: i128) {
+        let current_total = Self::get_referral_total(env.clone(), referrer.clone());
+        let total = Self::checked_add(env, current_total, amount);
+        env.storage().persistent().set(&DataKey::Referral(referrer.clone()), &total);
+
+        let mut top_referrers: Vec<ReferralEntry> = env
+            .storage()
+            .instance()
+            .get(&TOP_REFERRERS)
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = top_referrers.iter().position(|entry| entry.referrer == referrer) {
+            top_referrers.remove(idx as u32);
+        }
+        Self::insert_top_referrer(&mut top_referrers, referrer, total);
+        env.storage().instance().set(&TOP_REFERRERS, &top_referrers);
+    }
+
+    // Insert referrer into leaderboard sorted by total (highest first), capped at TOP_REFERRERS_LIMIT
+    fn insert_top_referrer(top_referrers: &mut Vec<ReferralEntry>, referrer: Address, total: i128) {
+        let mut idx = top_referrers.len();
+        while idx > 0 && top_referrers.get(idx - 1).unwrap().total < total {
+            idx -= 1;
+        }
+        if idx < TOP_REFERRERS_LIMIT {
+            top_referrers.insert(idx, ReferralEntry { referrer, total });
+            if top_referrers.len() > TOP_REFERRERS_LIMIT {
+                top_referrers.pop_back();
+            }
+        }
+    }
+
+    /// Subscribe to donate `amount` every `period` seconds
+    /// Donor must approve an allowance for this contract on the XLM token
+    /// First collection is due immediately
+    pub fn subscribe(env: Env, donor: Address, amount: i128, period: u64) {
+        donor.require_auth();
+
+        Self::check_can_donate(&env, amount);
+
+        if period == 0 {
+            panic!("Period must be greater than 0");
+        }
+
+        let subscription = Subscription {
+            amount,
+            period,
+            next_due: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Subscription(donor), &subscription);
+    }
+
+    /// Collect a due subscription payment - callable by anyone (keeper)
+    /// Pulls the fixed amount from donor via `transfer_from`
+    /// Returns the amount collected
+    pub fn collect_subscription(env: Env, donor: Address) -> i128 {
+        let mut subscription = match Self::get_subscription(env.clone(), donor.clone()) {
+            Some(subscription) => subscription,
+            None => panic!("No active subscription"),
+        };
+
+        if env.ledger().timestamp() < subscription.next_due {
+            panic!("Subscription payment not due yet");
+        }
+
+        Self::check_can_donate(&env, subscription.amount);
+
+        // Pull funds using donor's allowance
+        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+        let xlm_token = token::Client::new(&env, &xlm_token_address);
+        let contract_address = env.current_contract_address();
+
+        xlm_token.transfer_from(&contract_address, &donor, &contract_address, &subscription.amount);
+
+        Self::record_donation(&env, &donor, subscription.amount);
+
+        // Schedule next payment
+        subscription.next_due = match subscription.next_due.checked_add(subscription.period) {
+            Some(next_due) => next_due,
+            None => panic_with_error!(&env, Error::Overflow),
+        };
+        env.storage().persistent().set(&DataKey::Subscription(donor), &subscription);
+
+        subscription.amount
+    }
+
+    /// Cancel subscription - donor only
+    pub fn cancel_subscription(env: Env, donor: Address) {
+        donor.require_auth();
+
+        let subscription_key = DataKey::Subscription(donor);
+        if !env.storage().persistent().has(&subscription_key) {
+            panic!("No active subscription");
+        }
+
+        env.storage().persistent().remove(&subscription_key);
+    }
+
+    /// Get subscription for donor (None jika tidak subscribe)
+    pub fn get_subscription(env: Env, donor: Address) -> Option<Subscription> {
+        env.storage().persistent().get(&DataKey::Subscription(donor))
+    }
+
+    // Validate campaign is accepting donations and amount is valid
+    fn check_can_donate(env: &Env, amount: i128) {
+        Self::check_not_paused(env);
+
+        // Check if campaign started
+        if Self::is_upcoming(env.clone()) {
+            panic!("Campaign has not started");
+        }
+
+        // Check if campaign active
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if env.ledger().timestamp() > deadline {
+            panic!("Campaign has ended");
+        }
+
+        // Check if already withdrawn
+        let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
+        if withdrawn {
+            panic!("Campaign funds already withdrawn");
+        }
+
+        // Validate amount (min 0.1 XLM = 1,000,000 stroops)
+        if amount < 1_000_000 {
+            panic!("Minimum donation is 0.1 XLM");
+        }
+
+        // Reject before any transfer if total raised would overflow
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        Self::checked_add(env, total, amount);
+    }
+
+    // Guardian emergency stop blocks donate and withdraw
+    fn check_not_paused(env: &Env) {
+        if Self::is_refund_only(env.clone()) {
+            panic!("Campaign is in refund-only mode");
+        }
+
+        if Self::is_paused(env.clone()) {
+            panic!("Campaign is paused");
+        }
+    }
+
+    // Verify caller is the configured guardian
+    fn require_guardian(env: &Env, guardian: &Address) {
+        guardian.require_auth();
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if config.guardian != Some(guardian.clone()) {
+            panic!("Only guardian can perform this action");
+        }
+    }
+
+    /// Pause donate and withdraw - Guardian only
+    pub fn pause(env: Env, guardian: Address) {
+        Self::require_guardian(&env, &guardian);
+
+        env.storage().instance().set(&PAUSED, &true);
+        Paused { guardian }.publish(&env);
+    }
+
+    /// Unpause campaign - Guardian only
+    pub fn unpause(env: Env, guardian: Address) {
+        Self::require_guardian(&env, &guardian);
+
+        env.storage().instance().set(&PAUSED, &false);
+        Unpaused { guardian }.publish(&env);
+    }
+
+    /// Force campaign into refund-only mode - Guardian only, irreversible
+    /// Donors can refund their share of the remaining funds at any time
+    pub fn force_refund_only(env: Env, guardian: Address) {
+        Self::require_guardian(&env, &guardian);
+
+        env.storage().instance().set(&REFUND_ONLY, &true);
+        RefundOnly { guardian }.publish(&env);
+    }
+
+    /// Check apakah campaign di-pause oleh guardian
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&PAUSED).unwrap_or(false)
+    }
+
+    /// Check apakah campaign dalam refund-only mode
+    pub fn is_refund_only(env: Env) -> bool {
+        env.storage().instance().get(&REFUND_ONLY).unwrap_or(false)
+    }
+
+    /// Flag campaign during the dispute window - donors only
+    /// Weighted by donation amount; withdrawal freezes once flags exceed the threshold share
+    /// Returns true if the campaign is disputed after this flag
+    pub fn flag_dispute(env: Env, donor: Address) -> bool {
+        donor.require_auth();
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if config.dispute_window == 0 {
+            panic!("Dispute window not enabled");
+        }
+
+        if !Self::is_ended(env.clone()) || !Self::is_goal_reached(env.clone()) {
+            panic!("Dispute window has not started");
+        }
+
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if env.ledger().timestamp() > deadline.saturating_add(config.dispute_window) {
+            panic!("Dispute window has closed");
+        }
+
+        if Self::is_disputed(env.clone()) || Self::is_dispute_resolved(env.clone()) {
+            panic!("Dispute already raised");
+        }
+
+        let donation_amount = Self::get_donation(env.clone(), donor.clone());
+        if donation_amount <= 0 {
+            panic!("Only donors can flag");
+        }
+
+        let flagger_key = DataKey::DisputeFlagger(donor.clone());
+        if env.storage().persistent().has(&flagger_key) {
+            panic!("Already flagged");
+        }
+        env.storage().persistent().set(&flagger_key, &true);
+
+        let votes = Self::checked_add(&env, Self::get_dispute_votes(env.clone()), donation_amount);
+        env.storage().instance().set(&DISPUTE_VOTES, &votes);
+
+        // Freeze withdrawal once flagged share exceeds the threshold
+        let total_raised: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let threshold = Self::mul_div(&env, total_raised, config.dispute_threshold_bps as i128, BPS_DENOMINATOR as i128);
+        let disputed = votes > threshold;
+        if disputed {
+            env.storage().instance().set(&DISPUTED, &true);
+        }
+
+        DisputeFlagged {
+            donor,
+            votes,
+            disputed,
+        }
+        .publish(&env);
+
+        disputed
+    }
+
+    /// Resolve dispute - Arbiter only
+    /// payout = true unfreezes withdrawal, false switches campaign to refund-only
+    pub fn resolve_dispute(env: Env, arbiter: Address, payout: bool) {
+        arbiter.require_auth();
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if config.arbiter != Some(arbiter.clone()) {
+            panic!("Only arbiter can resolve dispute");
+        }
+
+        if !Self::is_disputed(env.clone()) {
+            panic!("Campaign is not disputed");
+        }
+
+        env.storage().instance().set(&DISPUTED, &false);
+        env.storage().instance().set(&DISPUTE_RESOLVED, &true);
+
+        if !payout {
+            env.storage().instance().set(&REFUND_ONLY, &true);
+        }
+
+        DisputeResolved { arbiter, payout }.publish(&env);
+    }
+
+    /// Check apakah withdrawal di-freeze karena dispute
+    pub fn is_disputed(env: Env) -> bool {
+        env.storage().instance().get(&DISPUTED).unwrap_or(false)
+    }
+
+    /// Check apakah dispute sudah di-resolve oleh arbiter
+    pub fn is_dispute_resolved(env: Env) -> bool {
+        env.storage().instance().get(&DISPUTE_RESOLVED).unwrap_or(false)
+    }
+
+    /// Get total donation weight flagging the campaign
+    pub fn get_dispute_votes(env: Env) -> i128 {
+        env.storage().instance().get(&DISPUTE_VOTES).unwrap_or(0)
+    }
+
+    // Checked i128 addition, panics with Error::Overflow
+    fn checked_add(env: &Env, a: i128, b: i128) -> i128 {
+        match a.checked_add(b) {
+            Some(result) => result,
+            None => panic_with_error!(env, Error::Overflow),
+        }
+    }
+
+    // Checked i128 subtraction, panics with Error::Overflow
+    fn checked_sub(env: &Env, a: i128, b: i128) -> i128 {
+        match a.checked_sub(b) {
+            Some(result) => result,
+            None => panic_with_error!(env, Error::Overflow),
+        }
+    }
+
+    // Computes floor(value * num / den) for non-negative inputs without
+    // overflowing the intermediate product: value = q * den + r, so
+    // value * num / den = q * num + r * num / den
+    fn mul_div(env: &Env, value: i128, num: i128, den: i128) -> i128 {
+        let whole = (value / den).checked_mul(num);
+        let part = (value % den).checked_mul(num).map(|r| r / den);
+        match (whole, part) {
+            (Some(whole), Some(part)) => Self::checked_add(env, whole, part),
+            _ => panic_with_error!(env, Error::Overflow),
+        }
+    }
+
+    // Record a received donation in totals, donor map and history
+    fn record_donation(env: &Env, donor: &Address, amount: i128) {
+        // Update total raised
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let total = Self::checked_add(env, total, amount);
+        env.storage().instance().set(&TOTAL_RAISED, &total);
+
+        // Track individual donor donations
+        let current_donation = Self::get_donation(env.clone(), donor.clone());
+        let donor_total = Self::checked_add(env, current_donation, amount);
+        env.storage().persistent().set(&DataKey::Donation(donor.clone()), &donor_total);
+
+        // New donor
+        if current_donation == 0 {
+            let donor_count = match Self::get_donor_count(env.clone()).checked_add(1) {
+                Some(donor_count) => donor_count,
+                None => panic_with_error!(env, Error::Overflow),
+            };
+            env.storage().instance().set(&DONOR_COUNT, &donor_count);
+        }
+
+        let donated = Self::checked_add(env, Self::get_donated(env), amount);
+        env.storage().instance().set(&DONATED, &donated);
+
+        // Add to donation history
+        let mut history: Vec<DonationRecord> = env.storage().instance().get(&DONATION_HISTORY).unwrap();
+        let record = DonationRecord {
+            id: history.len() as u64,
+            donor: donor.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        history.push_back(record.clone());
+        env.storage().instance().set(&DONATION_HISTORY, &history);
+
+        // Track largest single donations
+        let mut top_donations: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONATIONS)
+            .unwrap_or(Vec::new(env));
+        Self::insert_top_donation(&mut top_donations, record);
+        env.storage().instance().set(&TOP_DONATIONS, &top_donations);
+
+        // Update top donors leaderboard
+        let mut top_donors: Vec<DonorEntry> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONORS)
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = top_donors.iter().position(|entry| entry.donor == *donor) {
+            top_donors.remove(idx as u32);
+        }
+        Self::insert_top_donor(&mut top_donors, donor.clone(), donor_total);
+        env.storage().instance().set(&TOP_DONORS, &top_donors);
+
+        // Mint or update donor's receipt
+        // Receipt contract is chosen by the owner, a failing receipt must not block the donation
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if let Some(receipt) = config.receipt.clone() {
+            let _ = ReceiptClient::new(env, &receipt).try_mint(&env.current_contract_address(), donor, &amount);
+        }
+
+        Self::extend_deadline_if_late(env, &config);
+    }
+
+    // Anti-sniping: donation in the last extension_window seconds pushes the deadline back,
+    // capped at max_extension in total
+    fn extend_deadline_if_late(env: &Env, config: &CampaignConfig) {
+        if config.extension_window == 0 {
+            return;
+        }
+
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if deadline - env.ledger().timestamp() >= config.extension_window {
+            return;
+        }
+
+        let extended = Self::get_deadline_extension(env.clone());
+        let extension = config.extension_duration.min(config.max_extension - extended);
+        if extension == 0 {
+            return;
         }
 
-        // Store campaign metadata
-        env.storage().instance().set(&CAMPAIGN_TITLE, &title);
-        env.storage().instance().set(&CAMPAIGN_DESC, &description);
-        env.storage().instance().set(&CAMPAIGN_CATEGORY, &category);
-        env.storage().instance().set(&CAMPAIGN_OWNER, &owner);
-        env.storage().instance().set(&CAMPAIGN_GOAL, &goal);
-        env.storage().instance().set(&CAMPAIGN_DEADLINE, &deadline);
-        env.storage().instance().set(&CREATED_AT, &env.ledger().timestamp());
+        let (new_deadline, total_extension) = match (deadline.checked_add(extension), extended.checked_add(extension)) {
+            (Some(new_deadline), Some(total_extension)) => (new_deadline, total_extension),
+            _ => panic_with_error!(env, Error::Overflow),
+        };
+        env.storage().instance().set(&CAMPAIGN_DEADLINE, &new_deadline);
+        env.storage().instance().set(&DEADLINE_EXTENDED, &total_extension);
 
-        // Initialize campaign state
-        env.storage().instance().set(&TOTAL_RAISED, &0i128);
-        env.storage().instance().set(&XLM_TOKEN_ADDRESS, &xlm_token);
-        env.storage().instance().set(&IS_ALREADY_INIT, &true);
-        env.storage().instance().set(&WITHDRAWN, &false);
+        DeadlineExtended {
+            new_deadline,
+            total_extension,
+        }
+        .publish(env);
+    }
 
-        // Initialize empty donations map and history
-        let donations: Map<Address, i128> = Map::new(&env);
-        env.storage().instance().set(&DONATIONS, &donations);
+    // Insert donor into leaderboard sorted by total (highest first), capped at TOP_DONORS_LIMIT
+    fn insert_top_donor(top_donors: &mut Vec<DonorEntry>, donor: Address, total: i128) {
+        let mut idx = top_donors.len();
+        while idx > 0 && top_donors.get(idx - 1).unwrap().total < total {
+            idx -= 1;
+        }
+        if idx < TOP_DONORS_LIMIT {
+            top_donors.insert(idx, DonorEntry { donor, total });
+            if top_donors.len() > TOP_DONORS_LIMIT {
+                top_donors.pop_back();
+            }
+        }
+    }
 
-        let history: Vec<DonationRecord> = Vec::new(&env);
-        env.storage().instance().set(&DONATION_HISTORY, &history);
+    // Keep each donor's largest single donation, sorted by amount (highest first, earliest wins ties),
+    // capped at TOP_DONATIONS_LIMIT
+    fn insert_top_donation(top_donations: &mut Vec<DonationRecord>, record: DonationRecord) {
+        if let Some(idx) = top_donations.iter().position(|entry| entry.donor == record.donor) {
+            if top_donations.get(idx as u32).unwrap().amount >= record.amount {
+                return;
+            }
+            top_donations.remove(idx as u32);
+        }
+
+        let mut idx = top_donations.len();
+        while idx > 0 && top_donations.get(idx - 1).unwrap().amount < record.amount {
+            idx -= 1;
+        }
+        if idx < TOP_DONATIONS_LIMIT {
+            top_donations.insert(idx, record);
+            if top_donations.len() > TOP_DONATIONS_LIMIT {
+                top_donations.pop_back();
+            }
+        }
     }
 
-    /// Donate to campaign
-    pub fn donate(env: Env, donor: Address, amount: i128) {
-        // Verify donor authorization
-        donor.require_auth();
+    // Sum of non-refunded donations
+    fn get_donated(env: &Env) -> i128 {
+        env.storage().instance().get(&DONATED).unwrap_or(0)
+    }
 
-        // Check if campaign active
-        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
-        if env.ledger().timestamp() > deadline {
-            panic!("Campaign has ended");
+    // Drop a refunded donor from top donors and largest donations
+    // A full list may hide the next candidate, so it is rebuilt from the donation history
+    // using the remaining per-donor totals; a list that was not full already holds every donor
+    fn remove_from_top_lists(env: &Env, donor: &Address) {
+        let mut top_donors: Vec<DonorEntry> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONORS)
+            .unwrap_or(Vec::new(env));
+        let mut top_donations: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONATIONS)
+            .unwrap_or(Vec::new(env));
+        let donors_full = top_donors.len() == TOP_DONORS_LIMIT;
+        let donations_full = top_donations.len() == TOP_DONATIONS_LIMIT;
+
+        if let Some(idx) = top_donors.iter().position(|entry| entry.donor == *donor) {
+            top_donors.remove(idx as u32);
+        }
+        if let Some(idx) = top_donations.iter().position(|entry| entry.donor == *donor) {
+            top_donations.remove(idx as u32);
         }
 
-        // Check if already withdrawn
-        let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
-        if withdrawn {
-            panic!("Campaign funds already withdrawn");
+        if donors_full || donations_full {
+            let (rebuilt_donors, rebuilt_donations) = Self::build_top_lists(env);
+            if donors_full {
+                top_donors = rebuilt_donors;
+            }
+            if donations_full {
+                top_donations = rebuilt_donations;
+            }
         }
+        env.storage().instance().set(&TOP_DONORS, &top_donors);
+        env.storage().instance().set(&TOP_DONATIONS, &top_donations);
+    }
 
-        // Validate amount (min 0.1 XLM = 1,000,000 stroops)
-        if amount < 1_000_000 {
-            panic!("Minimum donation is 0.1 XLM");
+    // Build top donors and largest donations from the donation history,
+    // skipping donors that have been refunded
+    fn build_top_lists(env: &Env) -> (Vec<DonorEntry>, Vec<DonationRecord>) {
+        let history: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&DONATION_HISTORY)
+            .unwrap_or(Vec::new(env));
+        let mut totals: Map<Address, i128> = Map::new(env);
+        let mut top_donors: Vec<DonorEntry> = Vec::new(env);
+        let mut top_donations: Vec<DonationRecord> = Vec::new(env);
+        for record in history.iter() {
+            let total = match totals.get(record.donor.clone()) {
+                Some(total) => total,
+                None => {
+                    let total = Self::get_donation(env.clone(), record.donor.clone());
+                    totals.set(record.donor.clone(), total);
+                    if total > 0 {
+                        Self::insert_top_donor(&mut top_donors, record.donor.clone(), total);
+                    }
+                    total
+                }
+            };
+            if total > 0 {
+                Self::insert_top_donation(&mut top_donations, record);
+            }
         }
+        (top_donors, top_donations)
+    }
 
-        // Get XLM token contract and contract address
-        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
-        let xlm_token = token::Client::new(&env, &xlm_token_address);
-        let contract_address = env.current_contract_address();
+    /// Upgrade campaign contract code - Guardian (platform) only,
+    /// or the owner if the campaign was created without a guardian
+    /// Storage is kept; call `migrate` afterwards to convert old layouts
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if config.guardian.is_some() {
+            Self::require_guardian(&env, &caller);
+        } else {
+            caller.require_auth();
 
-        // Transfer XLM from donor to contract
-        xlm_token.transfer(&donor, &contract_address, &amount);
+            let owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+            if caller != owner {
+                panic!("Only owner can upgrade a campaign without guardian");
+            }
+        }
 
-        // Update total raised
-        let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-        total += amount;
-        env.storage().instance().set(&TOTAL_RAISED, &total);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
 
-        // Track individual donor donations
-        let mut donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        let current_donation = donations.get(donor.clone()).unwrap_or(0);
-        donations.set(donor.clone(), current_donation + amount);
-        env.storage().instance().set(&DONATIONS, &donations);
+    /// Get storage schema version (1 = layout before versioning)
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION).unwrap_or(1)
+    }
 
-        // Add to donation history
-        let mut history: Vec<DonationRecord> = env.storage().instance().get(&DONATION_HISTORY).unwrap();
-        let record = DonationRecord {
-            donor: donor.clone(),
-            amount,
-            timestamp: env.ledger().timestamp(),
-        };
-        history.push_back(record);
-        env.storage().instance().set(&DONATION_HISTORY, &history);
+    /// Migrate storage from older layouts to the current schema version
+    /// v1 -> v2: donations map moves from instance to persistent per-donor entries,
+    /// history records get sequence ids, donor statistics are rebuilt, and newer settings get their defaults
+    /// Returns the schema version after migration
+    pub fn migrate(env: Env) -> u32 {
+        if !Self::get_is_already_init(env.clone()) {
+            panic!("Campaign not initialized");
+        }
+
+        let version = Self::get_schema_version(env.clone());
+
+        if version < 2 {
+            // Move donations to persistent storage
+            let donations: Map<Address, i128> = env
+                .storage()
+                .instance()
+                .get(&DONATIONS)
+                .unwrap_or(Map::new(&env));
+            let mut donor_count: u32 = 0;
+            let mut donated: i128 = 0;
+            for (donor, amount) in donations.iter() {
+                if amount > 0 {
+                    env.storage().persistent().set(&DataKey::Donation(donor), &amount);
+                    donor_count += 1;
+                    donated = Self::checked_add(&env, donated, amount);
+                }
+            }
+            env.storage().instance().remove(&DONATIONS);
+            env.storage().instance().set(&DONOR_COUNT, &donor_count);
+            env.storage().instance().set(&DONATED, &donated);
+
+            // Add sequence ids to history records
+            let legacy_history: Vec<LegacyDonationRecord> = env
+                .storage()
+                .instance()
+                .get(&DONATION_HISTORY)
+                .unwrap_or(Vec::new(&env));
+            let mut history: Vec<DonationRecord> = Vec::new(&env);
+            for legacy in legacy_history.iter() {
+                let record = DonationRecord {
+                    id: history.len() as u64,
+                    donor: legacy.donor,
+                    amount: legacy.amount,
+                    timestamp: legacy.timestamp,
+                };
+                history.push_back(record);
+            }
+            env.storage().instance().set(&DONATION_HISTORY, &history);
+
+            // Top donors and largest donations from the migrated history
+            let (top_donors, top_donations) = Self::build_top_lists(&env);
+            env.storage().instance().set(&TOP_DONORS, &top_donors);
+            env.storage().instance().set(&TOP_DONATIONS, &top_donations);
+
+            // Defaults for settings introduced after v1
+            if !env.storage().instance().has(&PAYOUT_SPLITS) {
+                let owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+                let splits = vec![
+                    &env,
+                    PayoutSplit {
+                        recipient: owner,
+                        bps: BPS_DENOMINATOR,
+                    },
+                ];
+                env.storage().instance().set(&PAYOUT_SPLITS, &splits);
+            }
+            if !env.storage().instance().has(&CAMPAIGN_CONFIG) {
+                let config = CampaignConfig {
+                    vesting_duration: 0,
+                    receipt: None,
+                    guardian: None,
+                    dispute_window: 0,
+                    dispute_threshold_bps: 0,
+                    arbiter: None,
+                    start_time: None,
+                    extension_window: 0,
+                    extension_duration: 0,
+                    max_extension: 0,
+                    router: None,
+                };
+                env.storage().instance().set(&CAMPAIGN_CONFIG, &config);
+            }
+            if !env.storage().instance().has(&CLAIMED) {
+                // v1 withdraw paid out everything at once
+                let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
+                let claimed: i128 = if withdrawn {
+                    env.storage().instance().get(&TOTAL_RAISED).unwrap_or(0)
+                } else {
+                    0
+                };
+                env.storage().instance().set(&CLAIMED, &claimed);
+            }
+        }
+
+        env.storage().instance().set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+        CURRENT_SCHEMA_VERSION
     }
 
     /// Get total amount yang sudah terkumpul
@@ -160,8 +1192,7 @@ impl CrowdfundingContract {
     /// Get berapa banyak specific donor sudah donate
     /// Frontend perlu pass: donor address
     pub fn get_donation(env: Env, donor: Address) -> i128 {
-        let donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        donations.get(donor).unwrap_or(0)
+        env.storage().persistent().get(&DataKey::Donation(donor)).unwrap_or(0)
     }
 
     // Get initialization status - for frontend to check if contract is initialized
@@ -181,6 +1212,11 @@ impl CrowdfundingContract {
         env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap_or(0)
     }
 
+    /// Get total seconds deadline sudah diperpanjang oleh late donations
+    pub fn get_deadline_extension(env: Env) -> u64 {
+        env.storage().instance().get(&DEADLINE_EXTENDED).unwrap_or(0)
+    }
+
     /// Check apakah campaign goal sudah tercapai
     /// Returns true jika total raised >= goal
     pub fn is_goal_reached(env: Env) -> bool {
@@ -196,6 +1232,26 @@ impl CrowdfundingContract {
         env.ledger().timestamp() > deadline
     }
 
+    /// Check apakah campaign belum mulai (scheduled launch)
+    /// Returns true jika current time < start_time
+    pub fn is_upcoming(env: Env) -> bool {
+        match Self::get_config(env.clone()).start_time {
+            Some(start_time) => env.ledger().timestamp() < start_time,
+            None => false,
+        }
+    }
+
+    /// Get campaign status: Upcoming sebelum start_time, Ended setelah deadline
+    pub fn get_status(env: Env) -> CampaignStatus {
+        if Self::is_upcoming(env.clone()) {
+            CampaignStatus::Upcoming
+        } else if Self::is_ended(env) {
+            CampaignStatus::Ended
+        } else {
+            CampaignStatus::Active
+        }
+    }
+
     /// Calculate progress percentage dari campaign
     /// Returns (total_raised * 100) / goal
     /// Returns 0 jika goal adalah 0 (untuk avoid division by zero)
@@ -207,7 +1263,20 @@ impl CrowdfundingContract {
             return 0;
         }
 
-        (total_raised * 100) / goal
+        Self::mul_div(&env, total_raised, 100, goal)
+    }
+
+    /// Calculate progress in basis points (10000 = 100%)
+    /// Returns 0 jika goal adalah 0
+    pub fn get_progress_bps(env: Env) -> i128 {
+        let total_raised: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap_or(0);
+        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap_or(0);
+
+        if goal == 0 {
+            return 0;
+        }
+
+        Self::mul_div(&env, total_raised, BPS_DENOMINATOR as i128, goal)
     }
 
     /// Get owner address
@@ -225,7 +1294,162 @@ impl CrowdfundingContract {
             goal: env.storage().instance().get(&CAMPAIGN_GOAL).unwrap(),
             deadline: env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap(),
             created_at: env.storage().instance().get(&CREATED_AT).unwrap(),
+            start_time: Self::get_config(env.clone()).start_time,
+        }
+    }
+
+    /// Get top N donors by cumulative donation (highest first, max 10)
+    pub fn get_top_donors(env: Env, n: u32) -> Vec<DonorEntry> {
+        let top_donors: Vec<DonorEntry> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONORS)
+            .unwrap_or(Vec::new(&env));
+        top_donors.slice(0..n.min(top_donors.len()))
+    }
+
+    /// Get number of unique donors with a non-refunded donation
+    pub fn get_donor_count(env: Env) -> u32 {
+        env.storage().instance().get(&DONOR_COUNT).unwrap_or(0)
+    }
+
+    /// Get average donation per donor (non-refunded donations only)
+    /// Returns 0 jika belum ada donor
+    pub fn get_average_donation(env: Env) -> i128 {
+        let donor_count = Self::get_donor_count(env.clone());
+        if donor_count == 0 {
+            return 0;
+        }
+        Self::get_donated(&env) / donor_count as i128
+    }
+
+    /// Get largest single donation of a non-refunded donor (None jika belum ada donasi)
+    pub fn get_largest_donation(env: Env) -> Option<DonationRecord> {
+        let top_donations: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&TOP_DONATIONS)
+            .unwrap_or(Vec::new(&env));
+        top_donations.first()
+    }
+
+    /// Get total donations brought in by referrer
+    pub fn get_referral_total(env: Env, referrer: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::Referral(referrer)).unwrap_or(0)
+    }
+
+    /// Get top N referrers by total referred amount (highest first, max 10)
+    pub fn get_top_referrers(env: Env, n: u32) -> Vec<ReferralEntry> {
+        let top_referrers: Vec<ReferralEntry> = env
+            .storage()
+            .instance()
+            .get(&TOP_REFERRERS)
+            .unwrap_or(Vec::new(&env));
+        top_referrers.slice(0..n.min(top_referrers.len()))
+    }
+
+    /// Get payout split configuration
+    pub fn get_splits(env: Env) -> Vec<PayoutSplit> {
+        env.storage()
+            .instance()
+            .get(&PAYOUT_SPLITS)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get campaign configuration
+    pub fn get_config(env: Env) -> CampaignConfig {
+        env.storage().instance().get(&CAMPAIGN_CONFIG).unwrap()
+    }
+
+    /// Get amount of raised funds vested so far
+    /// Vesting starts at deadline and is linear over `vesting_duration`
+    /// Without vesting, everything is vested once the campaign ends
+    pub fn get_vested_amount(env: Env) -> i128 {
+        if Self::is_stream_halted(env.clone()) {
+            return env.storage().instance().get(&VESTED_AT_HALT).unwrap_or(0);
+        }
+
+        let total_raised: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap_or(0);
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap_or(0);
+        let now = env.ledger().timestamp();
+
+        if now <= deadline {
+            return 0;
+        }
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        let elapsed = now - deadline;
+
+        if config.vesting_duration == 0 || elapsed >= config.vesting_duration {
+            return total_raised;
+        }
+
+        Self::mul_div(&env, total_raised, elapsed as i128, config.vesting_duration as i128)
+    }
+
+    /// Get amount already claimed by owner
+    pub fn get_claimed_amount(env: Env) -> i128 {
+        env.storage().instance().get(&CLAIMED).unwrap_or(0)
+    }
+
+    /// Check apakah vesting stream sudah di-halt oleh donors
+    pub fn is_stream_halted(env: Env) -> bool {
+        env.storage().instance().get(&HALTED).unwrap_or(false)
+    }
+
+    /// Get total donation weight voting to halt the stream
+    pub fn get_halt_votes(env: Env) -> i128 {
+        env.storage().instance().get(&HALT_VOTES).unwrap_or(0)
+    }
+
+    /// Vote to halt the vesting stream - donors only
+    /// Weighted by donation amount; halts once votes exceed 50% of total raised
+    /// Returns true if the stream is halted after this vote
+    pub fn vote_halt(env: Env, donor: Address) -> bool {
+        donor.require_auth();
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if config.vesting_duration == 0 {
+            panic!("Vesting not enabled");
+        }
+
+        if !Self::is_ended(env.clone()) || !Self::is_goal_reached(env.clone()) {
+            panic!("Vesting stream has not started");
+        }
+
+        if Self::is_stream_halted(env.clone()) {
+            panic!("Stream already halted");
+        }
+
+        let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
+        if withdrawn {
+            panic!("Funds already withdrawn");
+        }
+
+        let donation_amount = Self::get_donation(env.clone(), donor.clone());
+        if donation_amount <= 0 {
+            panic!("Only donors can vote");
+        }
+
+        let voter_key = DataKey::HaltVoter(donor);
+        if env.storage().persistent().has(&voter_key) {
+            panic!("Already voted");
+        }
+        env.storage().persistent().set(&voter_key, &true);
+
+        let votes = Self::checked_add(&env, Self::get_halt_votes(env.clone()), donation_amount);
+        env.storage().instance().set(&HALT_VOTES, &votes);
+
+        // Majority reached: freeze vested amount, remainder becomes refundable
+        let total_raised: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        if votes > Self::checked_sub(&env, total_raised, votes) {
+            let vested = Self::get_vested_amount(env.clone());
+            env.storage().instance().set(&VESTED_AT_HALT, &vested);
+            env.storage().instance().set(&HALTED, &true);
+            return true;
         }
+
+        false
     }
 
     /// Get donation history (paginated)
@@ -263,7 +1487,99 @@ impl CrowdfundingContract {
         result
     }
 
+    /// Get donation history using a stable cursor (sequence id)
+    /// `cursor` is exclusive: oldest first returns ids after it, newest first returns ids before it
+    /// Optional `from_ts` / `to_ts` filter by timestamp (inclusive)
+    pub fn get_donations(
+        env: Env,
+        cursor: Option<u64>,
+        limit: u32,
+        oldest_first: bool,
+        from_ts: Option<u64>,
+        to_ts: Option<u64>,
+    ) -> Vec<DonationRecord> {
+        let history: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&DONATION_HISTORY)
+            .unwrap_or(Vec::new(&env));
+
+        let total_len = history.len() as u64;
+        let mut result = Vec::new(&env);
+
+        if limit == 0 {
+            return result;
+        }
+
+        if oldest_first {
+            let start = cursor.map_or(0, |c| c.saturating_add(1));
+            for id in start..total_len {
+                let record = history.get(id as u32).unwrap();
+                if to_ts.is_some_and(|to| record.timestamp > to) {
+                    break;
+                }
+                if from_ts.is_some_and(|from| record.timestamp < from) {
+                    continue;
+                }
+                result.push_back(record);
+                if result.len() >= limit {
+                    break;
+                }
+            }
+        } else {
+            let end = cursor.map_or(total_len, |c| c.min(total_len));
+            for id in (0..end).rev() {
+                let record = history.get(id as u32).unwrap();
+                if from_ts.is_some_and(|from| record.timestamp < from) {
+                    break;
+                }
+                if to_ts.is_some_and(|to| record.timestamp > to) {
+                    continue;
+                }
+                result.push_back(record);
+                if result.len() >= limit {
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Get donations from a single donor (oldest first)
+    /// `cursor` is exclusive: returns records with id after it
+    pub fn get_donations_by_donor(env: Env, donor: Address, cursor: Option<u64>, limit: u32) -> Vec<DonationRecord> {
+        let history: Vec<DonationRecord> = env
+            .storage()
+            .instance()
+            .get(&DONATION_HISTORY)
+            .unwrap_or(Vec::new(&env));
+
+        let total_len = history.len() as u64;
+        let mut result = Vec::new(&env);
+
+        if limit == 0 {
+            return result;
+        }
+
+        let start = cursor.map_or(0, |c| c.saturating_add(1));
+        for id in start..total_len {
+            let record = history.get(id as u32).unwrap();
+            if record.donor == donor {
+                result.push_back(record);
+                if result.len() >= limit {
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
     /// Withdraw funds - Owner only, after goal reached + ended
+    /// Funds are distributed to payout splits, rounding dust goes to owner
+    /// With vesting enabled, only the vested (unclaimed) portion is paid out
+    /// Returns the amount paid out by this call
     pub fn withdraw(env: Env, owner: Address) -> i128 {
         // Authorization check
         owner.require_auth();
@@ -274,6 +1590,9 @@ impl CrowdfundingContract {
             panic!("Only owner can withdraw");
         }
 
+        // Check guardian emergency stop
+        Self::check_not_paused(&env);
+
         // Check if campaign ended
         if !Self::is_ended(env.clone()) {
             panic!("Campaign has not ended yet");
@@ -284,6 +1603,20 @@ impl CrowdfundingContract {
             panic!("Goal not reached, cannot withdraw");
         }
 
+        // Check dispute status
+        if Self::is_disputed(env.clone()) {
+            panic!("Campaign is under dispute");
+        }
+
+        let config: CampaignConfig = Self::get_config(env.clone());
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if config.dispute_window > 0
+            && !Self::is_dispute_resolved(env.clone())
+            && env.ledger().timestamp() <= deadline.saturating_add(config.dispute_window)
+        {
+            panic!("Dispute window still open");
+        }
+
         // Check if already withdrawn
         let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
         if withdrawn {
@@ -297,39 +1630,81 @@ impl CrowdfundingContract {
             panic!("No funds to withdraw");
         }
 
-        // Transfer funds to owner
+        // Only the vested portion that hasn't been claimed yet
+        let claimed = Self::get_claimed_amount(env.clone());
+        let vested = Self::get_vested_amount(env.clone());
+        let claimable = Self::checked_sub(&env, vested, claimed);
+
+        if claimable <= 0 {
+            panic!("No vested funds to claim");
+        }
+
+        Self::distribute_payout(&env, &owner, claimable);
+
+        let claimed = Self::checked_add(&env, claimed, claimable);
+        env.storage().instance().set(&CLAIMED, &claimed);
+
+        // Mark as withdrawn once everything claimable has been paid out
+        let claim_cap = if Self::is_stream_halted(env.clone()) {
+            vested
+        } else {
+            total_raised
+        };
+        if claimed >= claim_cap {
+            env.storage().instance().set(&WITHDRAWN, &true);
+        }
+
+        claimable
+    }
+
+    // Distribute `amount` to payout recipients, rounding dust goes to owner
+    fn distribute_payout(env: &Env, owner: &Address, amount: i128) {
         let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
-        let xlm_token = token::Client::new(&env, &xlm_token_address);
+        let xlm_token = token::Client::new(env, &xlm_token_address);
         let contract_address = env.current_contract_address();
 
-        xlm_token.transfer(&contract_address, &owner, &total_raised);
+        let splits = Self::get_splits(env.clone());
+        let mut distributed: i128 = 0;
 
-        // Mark as withdrawn
-        env.storage().instance().set(&WITHDRAWN, &true);
+        for split in splits.iter() {
+            let share = Self::mul_div(env, amount, split.bps as i128, BPS_DENOMINATOR as i128);
+            if share > 0 {
+                xlm_token.transfer(&contract_address, &split.recipient, &share);
+                distributed = Self::checked_add(env, distributed, share);
+            }
+        }
 
-        total_raised
+        // Rounding dust goes to the primary owner
+        let dust = Self::checked_sub(env, amount, distributed);
+        if dust > 0 {
+            xlm_token.transfer(&contract_address, owner, &dust);
+        }
     }
 
     /// Refund function - allow donors to get their money back
     /// Only works if campaign ended AND goal NOT reached
+    /// If the vesting stream was halted, donors get their pro-rata share of the unvested remainder
+    /// In refund-only mode (guardian), donors get their pro-rata share of unclaimed funds anytime
     /// Returns the amount refunded to the donor
     pub fn refund(env: Env, donor: Address) -> i128 {
         // Step 1: Authorization - donor must authorize this action
         donor.require_auth();
 
+        let refund_only = Self::is_refund_only(env.clone());
+        let halted = Self::is_stream_halted(env.clone());
+
         // Step 2: Validation - check campaign has ended
-        if !Self::is_ended(env.clone()) {
+        if !Self::is_ended(env.clone()) && !refund_only {
             panic!("Campaign belum berakhir");
         }
 
-        // Step 3: Validation - check goal NOT reached
-        if Self::is_goal_reached(env.clone()) {
+        // Step 3: Validation - check goal NOT reached (kecuali stream di-halt / refund-only)
+        if Self::is_goal_reached(env.clone()) && !halted && !refund_only {
             panic!("Goal sudah tercapai, tidak bisa refund");
         }
 
         // Step 4: Get donor's donation amount
-        let donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        let donation_amount = donations.get(donor.clone()).unwrap_or(0);
+        let donation_amount = Self::get_donation(env.clone(), donor.clone());
 
         // Step 5: Validate donor has actual donation
         if donation_amount <= 0 {
@@ -341,21 +1716,54 @@ impl CrowdfundingContract {
         let xlm_token = token::Client::new(&env, &xlm_token_address);
         let contract_address = env.current_contract_address();
 
+        // Refund-only: unclaimed funds are refundable, pro-rata
+        // Halted stream: only the unvested remainder is refundable, pro-rata
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let pro_rata = halted || refund_only;
+        let refund_amount = if refund_only {
+            let claimed = Self::get_claimed_amount(env.clone());
+            Self::mul_div(&env, donation_amount, Self::checked_sub(&env, total, claimed), total)
+        } else if halted {
+            let vested: i128 = env.storage().instance().get(&VESTED_AT_HALT).unwrap_or(0);
+            Self::mul_div(&env, donation_amount, Self::checked_sub(&env, total, vested), total)
+        } else {
+            donation_amount
+        };
+
         // Transfer from contract back to donor
-        xlm_token.transfer(&contract_address, &donor, &donation_amount);
+        if refund_amount > 0 {
+            xlm_token.transfer(&contract_address, &donor, &refund_amount);
+        }
+
+        // Step 7: Remove donor's donation
+        env.storage().persistent().remove(&DataKey::Donation(donor.clone()));
+
+        // Update donor stats
+        let donor_count = match Self::get_donor_count(env.clone()).checked_sub(1) {
+            Some(donor_count) => donor_count,
+            None => panic_with_error!(&env, Error::Overflow),
+        };
+        env.storage().instance().set(&DONOR_COUNT, &donor_count);
+        let donated = Self::checked_sub(&env, Self::get_donated(&env), donation_amount);
+        env.storage().instance().set(&DONATED, &donated);
 
-        // Step 7: Remove donor from donations map
-        let mut donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        donations.set(donor.clone(), 0);
-        env.storage().instance().set(&DONATIONS, &donations);
+        // Refunded donor no longer counts for largest donations or top donors
+        Self::remove_from_top_lists(&env, &donor);
+
+        // Burn donor's receipt, a failing receipt must not block the refund
+        let config: CampaignConfig = Self::get_config(env.clone());
+        if let Some(receipt) = config.receipt {
+            let _ = ReceiptClient::new(&env, &receipt).try_burn(&contract_address, &donor);
+        }
 
         // Step 8: Update total_raised (subtract refunded amount)
-        let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-        total -= donation_amount;
-        env.storage().instance().set(&TOTAL_RAISED, &total);
+        // Pro-rata refunds keep total_raised as the base
+        if !pro_rata {
+            env.storage().instance().set(&TOTAL_RAISED, &Self::checked_sub(&env, total, donation_amount));
+        }
 
         // Step 9: Return refunded amount
-        donation_amount
+        refund_amount
     }
 }
 
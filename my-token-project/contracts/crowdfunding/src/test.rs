@@ -1,17 +1,53 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Ledger, token, Address, Env};
+use soroban_sdk::{testutils::Address as _, testutils::Events, testutils::Ledger, token, Address, Env, IntoVal, Val};
 
 const XLM_CONTRACT_TESTNET: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
 
 // Helper function to create and setup a mock token for testing
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> (Address, token::StellarAssetClient<'a>) {
     let token_address = env.register_stellar_asset_contract_v2(admin.clone());
-    let token = token::StellarAssetClient::new(&env, &token_address.address());
+    let token = token::StellarAssetClient::new(env, &token_address.address());
     (token_address.address(), token)
 }
 
+// Helper function to build default campaign config (no vesting)
+fn default_config() -> CampaignConfig {
+    CampaignConfig {
+        vesting_duration: 0,
+        receipt: None,
+        guardian: None,
+        dispute_window: 0,
+        dispute_threshold_bps: 0,
+        arbiter: None,
+        start_time: None,
+        extension_window: 0,
+        extension_duration: 0,
+        max_extension: 0,
+        router: None,
+    }
+}
+
+// Helper function to build core campaign parameters
+fn campaign_params(
+    title: &String,
+    description: &String,
+    goal: i128,
+    deadline: u64,
+    category: &Symbol,
+    xlm_token: &Address,
+) -> CampaignParams {
+    CampaignParams {
+        title: title.clone(),
+        description: description.clone(),
+        goal,
+        deadline,
+        category: category.clone(),
+        xlm_token: xlm_token.clone(),
+    }
+}
+
 // Helper function to initialize campaign with default metadata
 fn init_campaign(
     client: &CrowdfundingContractClient,
@@ -21,10 +57,11 @@ fn init_campaign(
     deadline: u64,
     xlm_token: &Address,
 ) {
-    let title = String::from_str(&env, "Test Campaign");
-    let description = String::from_str(&env, "This is a test campaign");
+    let title = String::from_str(env, "Test Campaign");
+    let description = String::from_str(env, "This is a test campaign");
     let category = symbol_short!("tech");
-    client.initialize(owner, &title, &description, &goal, &deadline, &category, xlm_token);
+    let splits = Vec::new(env);
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, xlm_token), &splits, &default_config());
 }
 
 // Test 1: Initialize campaign successfully
@@ -50,7 +87,7 @@ fn test_initialize_campaign() {
     let category = symbol_short!("tech");
 
     // Verify campaign initialized dengan benar
-    client.initialize(&owner, &title, &description, &goal, &deadline, &category, &xlm_token_address);
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &Vec::new(&env), &default_config());
 
     // Verify campaign initialized dengan benar
     assert_eq!(client.get_total_raised(), 0);
@@ -99,7 +136,7 @@ fn test_donate_zero_amount() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Try to donate 0 - should panic
-    client.donate(&donor, &0);
+    client.donate(&donor, &0, &None);
 }
 
 // Test 4: Cannot donate negative amount
@@ -122,7 +159,7 @@ fn test_donate_negative_amount() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Try to donate negative amount - should panic
-    client.donate(&donor, &-100_000_000);
+    client.donate(&donor, &-100_000_000, &None);
 }
 
 // Test 5: Campaign deadline validation
@@ -150,7 +187,7 @@ fn test_donate_after_deadline() {
     });
 
     // This should panic - will fail at XLM transfer but deadline check comes first
-    client.donate(&donor, &100_000_000);
+    client.donate(&donor, &100_000_000, &None);
 }
 
 // Test 6: Check initialization status before initialization
@@ -161,7 +198,7 @@ fn test_is_already_init_before_initialization() {
     let client = CrowdfundingContractClient::new(&env, &contract_id);
 
     // Before initialization, should return false
-    assert_eq!(client.get_is_already_init(), false);
+    assert!(!client.get_is_already_init());
 }
 
 // Test 7: Check initialization status after initialization
@@ -180,13 +217,13 @@ fn test_is_already_init_after_initialization() {
     env.mock_all_auths();
 
     // Before initialization
-    assert_eq!(client.get_is_already_init(), false);
+    assert!(!client.get_is_already_init());
 
     // Initialize the contract
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // After initialization, should return true
-    assert_eq!(client.get_is_already_init(), true);
+    assert!(client.get_is_already_init());
 }
 
 // Test 8: Initialization flag persists after other operations
@@ -209,14 +246,14 @@ fn test_is_already_init_persists() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Verify it's initialized
-    assert_eq!(client.get_is_already_init(), true);
+    assert!(client.get_is_already_init());
 
     // Check after querying other functions
     let _ = client.get_total_raised();
     let _ = client.get_donation(&donor);
 
     // Should still be true
-    assert_eq!(client.get_is_already_init(), true);
+    assert!(client.get_is_already_init());
 }
 
 
@@ -288,7 +325,7 @@ fn test_is_goal_reached_when_met() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Before reaching goal
-    assert_eq!(client.is_goal_reached(), false);
+    assert!(!client.is_goal_reached());
 }
 
 // Test 12: Check if goal is not reached
@@ -309,7 +346,7 @@ fn test_is_goal_not_reached() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Goal should not be reached (no donations yet)
-    assert_eq!(client.is_goal_reached(), false);
+    assert!(!client.is_goal_reached());
 }
 
 // Test 13: Check if campaign has ended
@@ -330,7 +367,7 @@ fn test_is_ended_after_deadline() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Before deadline
-    assert_eq!(client.is_ended(), false);
+    assert!(!client.is_ended());
 
     // Fast forward time past deadline
     env.ledger().with_mut(|li| {
@@ -338,7 +375,7 @@ fn test_is_ended_after_deadline() {
     });
 
     // After deadline
-    assert_eq!(client.is_ended(), true);
+    assert!(client.is_ended());
 }
 
 // Test 14: Check if campaign is not ended
@@ -359,7 +396,7 @@ fn test_is_not_ended_before_deadline() {
     init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
 
     // Campaign should not be ended (still within deadline)
-    assert_eq!(client.is_ended(), false);
+    assert!(!client.is_ended());
 }
 
 // Test 15: Get progress percentage with no donations
@@ -452,7 +489,7 @@ fn test_refund_success() {
 
     // Make donation (less than goal)
     let donation_amount = 30_000_000i128; // 3 XLM
-    client.donate(&donor, &donation_amount);
+    client.donate(&donor, &donation_amount, &None);
 
     // Verify donation recorded
     assert_eq!(client.get_donation(&donor), donation_amount);
@@ -464,8 +501,8 @@ fn test_refund_success() {
     });
 
     // Verify campaign ended and goal not reached
-    assert_eq!(client.is_ended(), true);
-    assert_eq!(client.is_goal_reached(), false);
+    assert!(client.is_ended());
+    assert!(!client.is_goal_reached());
 
     // Refund
     let refunded = client.refund(&donor);
@@ -498,7 +535,7 @@ fn test_refund_before_deadline() {
     token_client.mint(&donor, &100_000_000i128);
 
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
-    client.donate(&donor, &30_000_000);
+    client.donate(&donor, &30_000_000, &None);
 
     // Try refund before deadline - should panic
     client.refund(&donor);
@@ -528,7 +565,7 @@ fn test_refund_when_goal_reached() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate exactly goal amount
-    client.donate(&donor, &goal);
+    client.donate(&donor, &goal, &None);
 
     // Fast forward past deadline
     env.ledger().with_mut(|li| {
@@ -536,7 +573,7 @@ fn test_refund_when_goal_reached() {
     });
 
     // Verify goal reached
-    assert_eq!(client.is_goal_reached(), true);
+    assert!(client.is_goal_reached());
 
     // Try refund when goal reached - should panic
     client.refund(&donor);
@@ -592,7 +629,7 @@ fn test_get_metadata() {
 
     env.mock_all_auths();
 
-    client.initialize(&owner, &title, &description, &goal, &deadline, &category, &xlm_token_address);
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &Vec::new(&env), &default_config());
 
     // Get metadata
     let metadata = client.get_metadata();
@@ -631,10 +668,10 @@ fn test_withdraw_success() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate to reach goal
-    client.donate(&donor, &goal);
+    client.donate(&donor, &goal, &None);
 
     // Verify goal reached
-    assert_eq!(client.is_goal_reached(), true);
+    assert!(client.is_goal_reached());
     assert_eq!(client.get_total_raised(), goal);
 
     // Fast forward past deadline
@@ -643,7 +680,7 @@ fn test_withdraw_success() {
     });
 
     // Verify campaign ended
-    assert_eq!(client.is_ended(), true);
+    assert!(client.is_ended());
 
     // Withdraw funds
     let withdrawn = client.withdraw(&owner);
@@ -674,7 +711,7 @@ fn test_withdraw_before_deadline() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate to reach goal
-    client.donate(&donor, &goal);
+    client.donate(&donor, &goal, &None);
 
     // Try to withdraw before deadline - should panic
     client.withdraw(&owner);
@@ -702,7 +739,7 @@ fn test_withdraw_goal_not_reached() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate less than goal
-    client.donate(&donor, &50_000_000); // Only 5 XLM
+    client.donate(&donor, &50_000_000, &None); // Only 5 XLM
 
     // Fast forward past deadline
     env.ledger().with_mut(|li| {
@@ -736,7 +773,7 @@ fn test_withdraw_not_owner() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate to reach goal
-    client.donate(&donor, &goal);
+    client.donate(&donor, &goal, &None);
 
     // Fast forward past deadline
     env.ledger().with_mut(|li| {
@@ -770,7 +807,7 @@ fn test_withdraw_twice() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate to reach goal
-    client.donate(&donor, &goal);
+    client.donate(&donor, &goal, &None);
 
     // Fast forward past deadline
     env.ledger().with_mut(|li| {
@@ -809,7 +846,7 @@ fn test_donate_after_withdrawal() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // First donor reaches goal
-    client.donate(&donor1, &goal);
+    client.donate(&donor1, &goal, &None);
 
     // Fast forward past deadline
     env.ledger().with_mut(|li| {
@@ -820,7 +857,7 @@ fn test_donate_after_withdrawal() {
     client.withdraw(&owner);
 
     // Try to donate after withdrawal - should panic
-    client.donate(&donor2, &10_000_000);
+    client.donate(&donor2, &10_000_000, &None);
 }
 
 // Test 29: Donation history tracking
@@ -846,9 +883,9 @@ fn test_donation_history() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Make donations
-    client.donate(&donor1, &30_000_000);
-    client.donate(&donor2, &20_000_000);
-    client.donate(&donor1, &10_000_000); // Second donation from donor1
+    client.donate(&donor1, &30_000_000, &None);
+    client.donate(&donor2, &20_000_000, &None);
+    client.donate(&donor1, &10_000_000, &None); // Second donation from donor1
 
     // Get donation history (limit 10, offset 0)
     let history = client.get_donation_history(&10, &0);
@@ -894,7 +931,7 @@ fn test_donation_history_pagination() {
 
     // Make 5 donations
     for i in 1..=5 {
-        client.donate(&donor, &(i * 1_000_000));
+        client.donate(&donor, &(i * 1_000_000), &None);
     }
 
     // Get first 2 records (newest first)
@@ -984,10 +1021,2426 @@ fn test_overfunding_allowed() {
     init_campaign(&client, &env, &owner, goal, deadline, &token_address);
 
     // Donate 200% of goal
-    client.donate(&donor, &200_000_000); // 20 XLM
+    client.donate(&donor, &200_000_000, &None); // 20 XLM
 
     // Verify over-funding allowed
     assert_eq!(client.get_total_raised(), 200_000_000);
     assert_eq!(client.get_progress_percentage(), 200); // 200%
-    assert_eq!(client.is_goal_reached(), true);
-}
\ No newline at end of file
+    assert!(client.is_goal_reached());
+}
+
+// 💸 PAYOUT SPLIT TESTS
+
+// Test 34: Default split sends everything to owner
+#[test]
+fn test_get_splits_default() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    init_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address);
+
+    // Single split: owner with 100%
+    let splits = client.get_splits();
+    assert_eq!(splits.len(), 1);
+    assert_eq!(splits.get(0).unwrap().recipient, owner);
+    assert_eq!(splits.get(0).unwrap().bps, 10_000);
+}
+
+// Test 35: Withdraw distributes funds across splits, dust to owner
+#[test]
+fn test_withdraw_with_splits() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let partner1 = Address::generate(&env);
+    let partner2 = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    // 33.33% / 33.33% / 33.34% split
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: owner.clone(), bps: 3_334 },
+        PayoutSplit { recipient: partner1.clone(), bps: 3_333 },
+        PayoutSplit { recipient: partner2.clone(), bps: 3_333 },
+    ];
+
+    let title = String::from_str(&env, "Collab Campaign");
+    let description = String::from_str(&env, "Shared payout");
+    let category = symbol_short!("art");
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &splits, &default_config());
+
+    assert_eq!(client.get_splits().len(), 3);
+
+    // Odd amount so that rounding produces dust
+    let raised = 100_000_001i128;
+    client.donate(&donor, &raised, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    let withdrawn = client.withdraw(&owner);
+    assert_eq!(withdrawn, raised);
+
+    // Partners get their exact (floored) share
+    let partner_share = raised * 3_333 / 10_000;
+    assert_eq!(token.balance(&partner1), partner_share);
+    assert_eq!(token.balance(&partner2), partner_share);
+
+    // Owner gets own share plus rounding dust
+    assert_eq!(token.balance(&owner), raised - 2 * partner_share);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// Test 36: Splits must sum to 10000 bps
+#[test]
+#[should_panic(expected = "Splits must sum to 10000 bps")]
+fn test_initialize_invalid_splits_sum() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: owner.clone(), bps: 5_000 },
+        PayoutSplit { recipient: partner, bps: 4_000 },
+    ];
+
+    let title = String::from_str(&env, "Bad Split");
+    let description = String::from_str(&env, "Only 90%");
+    let category = symbol_short!("tech");
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &splits, &default_config());
+}
+
+// Test 37: Zero-share split recipients are rejected
+#[test]
+#[should_panic(expected = "Split share must be greater than 0")]
+fn test_initialize_zero_split() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: owner.clone(), bps: 10_000 },
+        PayoutSplit { recipient: partner, bps: 0 },
+    ];
+
+    let title = String::from_str(&env, "Bad Split");
+    let description = String::from_str(&env, "Zero share");
+    let category = symbol_short!("tech");
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &splits, &default_config());
+}
+
+
+// ⏳ VESTING TESTS
+
+// Helper function to initialize a vesting campaign funded by the given donors
+fn init_vesting_campaign(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    deadline: u64,
+    xlm_token: &Address,
+    vesting_duration: u64,
+) {
+    let title = String::from_str(env, "Vesting Campaign");
+    let description = String::from_str(env, "Funds stream to owner");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        vesting_duration,
+        ..default_config()
+    };
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, xlm_token), &Vec::new(env), &config);
+}
+
+// Test 38: Owner claims vested funds linearly over the vesting duration
+#[test]
+fn test_vesting_linear_withdraw() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let duration = 1000u64;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_vesting_campaign(&client, &env, &owner, goal, deadline, &token_address, duration);
+    client.donate(&donor, &goal, &None);
+
+    // Nothing vested at deadline
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline;
+    });
+    assert_eq!(client.get_vested_amount(), 0);
+
+    // 25% through the stream
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 250;
+    });
+    assert_eq!(client.get_vested_amount(), goal / 4);
+    assert_eq!(client.withdraw(&owner), goal / 4);
+    assert_eq!(client.get_claimed_amount(), goal / 4);
+    assert_eq!(token.balance(&owner), goal / 4);
+
+    // 75% through: only the newly vested part is claimable
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 750;
+    });
+    assert_eq!(client.withdraw(&owner), goal / 2);
+
+    // Stream finished: remainder claimable
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + duration + 1;
+    });
+    assert_eq!(client.withdraw(&owner), goal / 4);
+    assert_eq!(token.balance(&owner), goal);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// Test 39: Cannot claim twice within the same vested amount
+#[test]
+#[should_panic(expected = "No vested funds to claim")]
+fn test_vesting_nothing_to_claim() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_vesting_campaign(&client, &env, &owner, goal, deadline, &token_address, 1000);
+    client.donate(&donor, &goal, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 500;
+    });
+
+    client.withdraw(&owner);
+
+    // Same timestamp, nothing new vested
+    client.withdraw(&owner);
+}
+
+// Test 40: Donor majority halts the stream and refunds the unvested remainder
+#[test]
+fn test_vesting_halt_and_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+
+    init_vesting_campaign(&client, &env, &owner, goal, deadline, &token_address, 1000);
+
+    // donor1 holds 60%, donor2 holds 40%
+    client.donate(&donor1, &60_000_000, &None);
+    client.donate(&donor2, &40_000_000, &None);
+
+    // 40% vested, owner claims it
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 400;
+    });
+    assert_eq!(client.withdraw(&owner), 40_000_000);
+
+    // Minority vote does not halt
+    assert!(!client.vote_halt(&donor2));
+    assert!(!client.is_stream_halted());
+    assert_eq!(client.get_halt_votes(), 40_000_000);
+
+    // 50% vested when majority is reached
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 500;
+    });
+    assert!(client.vote_halt(&donor1));
+    assert!(client.is_stream_halted());
+
+    // Vested amount is frozen from now on
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 900;
+    });
+    assert_eq!(client.get_vested_amount(), 50_000_000);
+
+    // Owner can still claim what vested before the halt
+    assert_eq!(client.withdraw(&owner), 10_000_000);
+
+    // Donors get pro-rata share of the unvested 50%
+    assert_eq!(client.refund(&donor1), 30_000_000);
+    assert_eq!(client.refund(&donor2), 20_000_000);
+
+    assert_eq!(token.balance(&owner), 50_000_000);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// Test 41: Only donors can vote to halt
+#[test]
+#[should_panic(expected = "Only donors can vote")]
+fn test_vote_halt_non_donor() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_vesting_campaign(&client, &env, &owner, goal, deadline, &token_address, 1000);
+    client.donate(&donor, &goal, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    client.vote_halt(&stranger);
+}
+
+// 🔁 SUBSCRIPTION TESTS
+
+// Test 42: Keeper collects subscription once per period
+#[test]
+fn test_subscription_collect() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 10_000;
+    let period = 1000u64;
+    let monthly = 5_000_000i128;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Donor approves allowance and subscribes
+    token.approve(&donor, &contract_id, &(monthly * 3), &1000);
+    client.subscribe(&donor, &monthly, &period);
+
+    let subscription = client.get_subscription(&donor).unwrap();
+    assert_eq!(subscription.amount, monthly);
+    assert_eq!(subscription.next_due, env.ledger().timestamp());
+
+    // First payment due immediately
+    assert_eq!(client.collect_subscription(&donor), monthly);
+
+    // Next period
+    env.ledger().with_mut(|li| {
+        li.timestamp += period;
+    });
+    client.collect_subscription(&donor);
+
+    assert_eq!(client.get_total_raised(), monthly * 2);
+    assert_eq!(client.get_donation(&donor), monthly * 2);
+    assert_eq!(token.balance(&contract_id), monthly * 2);
+    assert_eq!(client.get_donation_history(&10, &0).len(), 2);
+}
+
+// Test 43: Cannot collect before the period elapses
+#[test]
+#[should_panic(expected = "Subscription payment not due yet")]
+fn test_subscription_collect_not_due() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    token.approve(&donor, &contract_id, &20_000_000, &1000);
+    client.subscribe(&donor, &5_000_000, &1000);
+
+    client.collect_subscription(&donor);
+
+    // Same period - should panic
+    client.collect_subscription(&donor);
+}
+
+// Test 44: Cancelled subscription can no longer be collected
+#[test]
+#[should_panic(expected = "No active subscription")]
+fn test_subscription_cancel() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    token.approve(&donor, &contract_id, &20_000_000, &1000);
+    client.subscribe(&donor, &5_000_000, &1000);
+    client.cancel_subscription(&donor);
+
+    assert!(client.get_subscription(&donor).is_none());
+
+    client.collect_subscription(&donor);
+}
+
+// 🧾 RECEIPT TESTS
+
+// Test 45: Donations mint a receipt, refund burns it
+#[test]
+fn test_donation_receipt_mint_and_burn() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let receipt_id = env.register(receipt::ReceiptContract, ());
+    let receipt_client = receipt::ReceiptContractClient::new(&env, &receipt_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    let title = String::from_str(&env, "Receipt Campaign");
+    let description = String::from_str(&env, "Mints receipts");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        receipt: Some(receipt_id.clone()),
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &Vec::new(&env), &config);
+
+    // Two donations update the same receipt
+    client.donate(&donor, &20_000_000, &None);
+    client.donate(&donor, &10_000_000, &None);
+
+    let id = receipt_client.get_receipt_id(&contract_id, &donor).unwrap();
+    assert_eq!(receipt_client.owner_of(&id), donor);
+    assert_eq!(receipt_client.balance(&donor), 1);
+    assert_eq!(receipt_client.metadata(&id).amount, 30_000_000);
+
+    // Goal not reached, refund burns the receipt
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.refund(&donor);
+
+    assert_eq!(receipt_client.balance(&donor), 0);
+    assert_eq!(receipt_client.get_receipt_id(&contract_id, &donor), None);
+}
+
+// 📣 REFERRAL TESTS
+
+// Test 46: Referral totals and top referrers leaderboard
+#[test]
+fn test_referral_tracking() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let ambassador1 = Address::generate(&env);
+    let ambassador2 = Address::generate(&env);
+    let ambassador3 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    client.donate(&donor1, &10_000_000, &Some(ambassador1.clone()));
+    client.donate(&donor2, &30_000_000, &Some(ambassador2.clone()));
+    client.donate(&donor1, &15_000_000, &Some(ambassador1.clone()));
+    client.donate(&donor2, &5_000_000, &Some(ambassador3.clone()));
+    client.donate(&donor2, &50_000_000, &None);
+
+    assert_eq!(client.get_referral_total(&ambassador1), 25_000_000);
+    assert_eq!(client.get_referral_total(&ambassador2), 30_000_000);
+    assert_eq!(client.get_referral_total(&owner), 0);
+
+    // Top 2 referrers, highest first
+    let top = client.get_top_referrers(&2);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap().referrer, ambassador2);
+    assert_eq!(top.get(1).unwrap().referrer, ambassador1);
+    assert_eq!(top.get(1).unwrap().total, 25_000_000);
+
+    // Asking for more than available returns all
+    assert_eq!(client.get_top_referrers(&10).len(), 3);
+    assert_eq!(client.get_top_referrers(&0).len(), 0);
+}
+
+// Test 47: Donor cannot refer themselves
+#[test]
+#[should_panic(expected = "Cannot refer yourself")]
+fn test_referral_self() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    client.donate(&donor, &10_000_000, &Some(donor.clone()));
+}
+
+// 📊 DONOR STATISTICS TESTS
+
+// Test 48: Donor stats are maintained on donate
+#[test]
+fn test_donor_statistics() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let donor3 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+    token_client.mint(&donor3, &200_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // No donations yet
+    assert_eq!(client.get_donor_count(), 0);
+    assert_eq!(client.get_average_donation(), 0);
+    assert!(client.get_largest_donation().is_none());
+
+    client.donate(&donor1, &10_000_000, &None);
+    client.donate(&donor2, &25_000_000, &None);
+    client.donate(&donor3, &5_000_000, &None);
+    client.donate(&donor1, &20_000_000, &None); // donor1 cumulative 30M
+
+    assert_eq!(client.get_donor_count(), 3);
+    assert_eq!(client.get_average_donation(), 20_000_000);
+
+    // Largest single donation, not cumulative
+    let largest = client.get_largest_donation().unwrap();
+    assert_eq!(largest.donor, donor2);
+    assert_eq!(largest.amount, 25_000_000);
+
+    // Leaderboard by cumulative amount
+    let top = client.get_top_donors(&2);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap().donor, donor1);
+    assert_eq!(top.get(0).unwrap().total, 30_000_000);
+    assert_eq!(top.get(1).unwrap().donor, donor2);
+    assert_eq!(client.get_top_donors(&10).len(), 3);
+}
+
+// Test 49: Refunded donor leaves the stats
+#[test]
+fn test_donor_statistics_after_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    client.donate(&donor1, &40_000_000, &None);
+    client.donate(&donor2, &10_000_000, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    client.refund(&donor1);
+
+    assert_eq!(client.get_donor_count(), 1);
+    assert_eq!(client.get_average_donation(), 10_000_000);
+
+    let top = client.get_top_donors(&10);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top.get(0).unwrap().donor, donor2);
+}
+
+// 📜 DONATION HISTORY QUERY TESTS
+
+// Test 50: Cursor paging is stable under concurrent donations
+#[test]
+fn test_donations_cursor_paging() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &500_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    for i in 1..=5 {
+        client.donate(&donor, &(i * 1_000_000), &None);
+    }
+
+    // Oldest first: ids 0, 1
+    let page1 = client.get_donations(&None, &2, &true, &None, &None);
+    assert_eq!(page1.len(), 2);
+    assert_eq!(page1.get(0).unwrap().id, 0);
+    assert_eq!(page1.get(1).unwrap().amount, 2_000_000);
+
+    // New donation arrives between pages, next page is unaffected
+    client.donate(&donor, &6_000_000, &None);
+
+    let cursor = page1.get(1).unwrap().id;
+    let page2 = client.get_donations(&Some(cursor), &2, &true, &None, &None);
+    assert_eq!(page2.get(0).unwrap().id, 2);
+    assert_eq!(page2.get(1).unwrap().id, 3);
+
+    // Newest first from the end
+    let newest = client.get_donations(&None, &2, &false, &None, &None);
+    assert_eq!(newest.get(0).unwrap().id, 5);
+    assert_eq!(newest.get(1).unwrap().id, 4);
+
+    // Newest first before cursor
+    let older = client.get_donations(&Some(4), &10, &false, &None, &None);
+    assert_eq!(older.len(), 4);
+    assert_eq!(older.get(0).unwrap().id, 3);
+    assert_eq!(older.get(3).unwrap().id, 0);
+
+    // Cursor at the end returns nothing
+    assert_eq!(client.get_donations(&Some(5), &10, &true, &None, &None).len(), 0);
+}
+
+// Test 51: Time range filtering
+#[test]
+fn test_donations_time_range() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let start = env.ledger().timestamp();
+    let deadline = start + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &500_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Donations at start+0, +100, +200, +300
+    for i in 0..4u64 {
+        env.ledger().with_mut(|li| {
+            li.timestamp = start + i * 100;
+        });
+        client.donate(&donor, &1_000_000, &None);
+    }
+
+    let in_range = client.get_donations(&None, &10, &true, &Some(start + 100), &Some(start + 200));
+    assert_eq!(in_range.len(), 2);
+    assert_eq!(in_range.get(0).unwrap().id, 1);
+    assert_eq!(in_range.get(1).unwrap().id, 2);
+
+    let newest_in_range = client.get_donations(&None, &10, &false, &Some(start + 100), &None);
+    assert_eq!(newest_in_range.len(), 3);
+    assert_eq!(newest_in_range.get(0).unwrap().id, 3);
+}
+
+// Test 52: Donor-filtered history with cursor
+#[test]
+fn test_donations_by_donor() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &500_000_000i128);
+    token_client.mint(&donor2, &500_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    client.donate(&donor1, &1_000_000, &None); // id 0
+    client.donate(&donor2, &2_000_000, &None); // id 1
+    client.donate(&donor1, &3_000_000, &None); // id 2
+    client.donate(&donor1, &4_000_000, &None); // id 3
+
+    let page1 = client.get_donations_by_donor(&donor1, &None, &2);
+    assert_eq!(page1.len(), 2);
+    assert_eq!(page1.get(0).unwrap().id, 0);
+    assert_eq!(page1.get(1).unwrap().id, 2);
+
+    let page2 = client.get_donations_by_donor(&donor1, &Some(2), &2);
+    assert_eq!(page2.len(), 1);
+    assert_eq!(page2.get(0).unwrap().amount, 4_000_000);
+
+    assert_eq!(client.get_donations_by_donor(&donor2, &None, &10).len(), 1);
+}
+
+// 🔢 PRECISION & OVERFLOW TESTS
+
+// Test 53: Progress in basis points
+#[test]
+fn test_get_progress_bps() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 300_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &500_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    assert_eq!(client.get_progress_bps(), 0);
+
+    client.donate(&donor, &100_000_000, &None);
+
+    // 33.33% -> 3333 bps, 33%
+    assert_eq!(client.get_progress_bps(), 3_333);
+    assert_eq!(client.get_progress_percentage(), 33);
+}
+
+// Test 54: Extreme totals do not overflow progress and withdraw math
+#[test]
+fn test_extreme_amounts_progress_and_withdraw() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &i128::MAX);
+
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: owner.clone(), bps: 1 },
+        PayoutSplit { recipient: partner.clone(), bps: 9_999 },
+    ];
+    let title = String::from_str(&env, "Whale Campaign");
+    let description = String::from_str(&env, "Extreme amounts");
+    let category = symbol_short!("tech");
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &splits, &default_config());
+
+    client.donate(&donor, &i128::MAX, &None);
+
+    // goal = 10^8 so bps = total / 10^4 exactly
+    assert_eq!(client.get_progress_bps(), i128::MAX / 10_000);
+    assert_eq!(client.get_progress_percentage(), i128::MAX / 1_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    assert_eq!(client.withdraw(&owner), i128::MAX);
+    assert_eq!(token.balance(&owner) + token.balance(&partner), i128::MAX);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// Test 55: Donation that would overflow total raised fails with typed error
+#[test]
+fn test_donate_overflow() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &(i128::MAX - 1_000_000));
+    token_client.mint(&donor2, &10_000_000);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    client.donate(&donor1, &(i128::MAX - 1_000_000), &None);
+
+    // Exactly reaching i128::MAX is fine
+    client.donate(&donor2, &1_000_000, &None);
+    assert_eq!(client.get_total_raised(), i128::MAX);
+
+    // One more stroop overflows
+    let result = client.try_donate(&donor2, &1_000_000, &None);
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+    assert_eq!(client.get_donation(&donor2), 1_000_000);
+}
+
+// Simple xorshift generator for property tests
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+fn random_i128(state: &mut u64) -> i128 {
+    let high = next_random(state) as u128;
+    let low = next_random(state) as u128;
+    // Non-negative, spread over the full magnitude range
+    let value = ((high << 64) | low) >> 1;
+    (value >> (next_random(state) % 127)) as i128
+}
+
+// Test 56: Property test for overflow-safe mul_div with extreme i128 values
+#[test]
+fn test_mul_div_properties() {
+    let env = Env::default();
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    let mut values = vec![&env, 0i128, 1, 9_999, 10_000, i128::MAX - 1, i128::MAX];
+    for _ in 0..500 {
+        values.push_back(random_i128(&mut state));
+    }
+
+    for value in values.iter() {
+        for bps in [0i128, 1, 3_333, 5_000, 9_999, 10_000] {
+            let share = CrowdfundingContract::mul_div(&env, value, bps, 10_000);
+            let rest = CrowdfundingContract::mul_div(&env, value, 10_000 - bps, 10_000);
+
+            // Shares never exceed the input and lose at most one unit to rounding
+            assert!(share <= value);
+            assert!(value - (share + rest) <= 1);
+
+            // Matches naive math whenever the naive product fits
+            if let Some(product) = value.checked_mul(bps) {
+                assert_eq!(share, product / 10_000);
+            }
+        }
+
+        // Identity and scaling up (overfunded progress)
+        assert_eq!(CrowdfundingContract::mul_div(&env, value, 7, 7), value);
+        let den = (next_random(&mut state) as i128).max(1);
+        let num = 10_000i128;
+        if let Some(product) = value.checked_mul(num) {
+            assert_eq!(CrowdfundingContract::mul_div(&env, value, num, den), product / den);
+        }
+    }
+}
+
+// 🛡️ GUARDIAN TESTS
+
+// Helper function to initialize campaign with a guardian
+fn init_guarded_campaign(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    deadline: u64,
+    xlm_token: &Address,
+    guardian: &Address,
+) {
+    let title = String::from_str(env, "Guarded Campaign");
+    let description = String::from_str(env, "Has a platform guardian");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        guardian: Some(guardian.clone()),
+        ..default_config()
+    };
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, xlm_token), &Vec::new(env), &config);
+}
+
+// Test 57: Guardian pauses and unpauses donations
+#[test]
+fn test_guardian_pause_unpause() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+
+    client.pause(&guardian);
+
+    // Pause event emitted
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "paused"), &guardian).into_val(&env),
+                Map::<Symbol, Val>::new(&env).into_val(&env),
+            ),
+        ]
+    );
+    assert!(client.is_paused());
+
+    // Donations blocked while paused
+    assert!(client.try_donate(&donor, &10_000_000, &None).is_err());
+
+    client.unpause(&guardian);
+    assert!(!client.is_paused());
+
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_total_raised(), 10_000_000);
+}
+
+// Test 58: Paused campaign blocks withdraw
+#[test]
+#[should_panic(expected = "Campaign is paused")]
+fn test_guardian_pause_blocks_withdraw() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+    client.donate(&donor, &goal, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    client.pause(&guardian);
+    client.withdraw(&owner);
+}
+
+// Test 59: Only guardian can pause
+#[test]
+#[should_panic(expected = "Only guardian can perform this action")]
+fn test_pause_not_guardian() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &xlm_token_address, &guardian);
+
+    // Owner is not the guardian
+    client.pause(&owner);
+}
+
+// Test 60: Refund-only mode refunds donors before deadline even when goal reached
+#[test]
+fn test_guardian_force_refund_only() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+
+    client.donate(&donor1, &80_000_000, &None);
+    client.donate(&donor2, &40_000_000, &None);
+    assert!(client.is_goal_reached());
+
+    client.force_refund_only(&guardian);
+    assert!(client.is_refund_only());
+
+    // Donations and withdraw blocked
+    assert!(client.try_donate(&donor1, &10_000_000, &None).is_err());
+    assert!(client.try_withdraw(&owner).is_err());
+
+    // Full refunds before the deadline
+    assert_eq!(client.refund(&donor1), 80_000_000);
+    assert_eq!(client.refund(&donor2), 40_000_000);
+    assert_eq!(token.balance(&donor1), 200_000_000);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ⚖️ DISPUTE WINDOW TESTS
+
+// Helper function to initialize campaign with a dispute window
+fn init_disputable_campaign(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    deadline: u64,
+    xlm_token: &Address,
+    arbiter: &Address,
+) {
+    let title = String::from_str(env, "Disputable Campaign");
+    let description = String::from_str(env, "Has a dispute window");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        dispute_window: 500,
+        dispute_threshold_bps: 3_000, // 30%
+        arbiter: Some(arbiter.clone()),
+        ..default_config()
+    };
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, xlm_token), &Vec::new(env), &config);
+}
+
+// Test 61: Withdraw waits for the dispute window to close
+#[test]
+fn test_dispute_window_delays_withdraw() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_disputable_campaign(&client, &env, &owner, goal, deadline, &token_address, &arbiter);
+    client.donate(&donor, &goal, &None);
+
+    // Inside the window
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 500;
+    });
+    assert!(client.try_withdraw(&owner).is_err());
+
+    // Window closed without dispute
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 501;
+    });
+    assert_eq!(client.withdraw(&owner), goal);
+}
+
+// Test 62: Flags over threshold freeze withdrawal until arbiter approves payout
+#[test]
+fn test_dispute_resolved_toward_payout() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let donor3 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+    token_client.mint(&donor3, &200_000_000i128);
+
+    init_disputable_campaign(&client, &env, &owner, goal, deadline, &token_address, &arbiter);
+
+    client.donate(&donor1, &20_000_000, &None);
+    client.donate(&donor2, &20_000_000, &None);
+    client.donate(&donor3, &60_000_000, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 10;
+    });
+
+    // 20% is under the 30% threshold
+    assert!(!client.flag_dispute(&donor1));
+    assert!(!client.is_disputed());
+
+    // 40% exceeds it
+    assert!(client.flag_dispute(&donor2));
+    assert!(client.is_disputed());
+    assert_eq!(client.get_dispute_votes(), 40_000_000);
+
+    // Frozen even after the window closes
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1000;
+    });
+    assert!(client.try_withdraw(&owner).is_err());
+
+    client.resolve_dispute(&arbiter, &true);
+    assert!(!client.is_disputed());
+    assert!(client.is_dispute_resolved());
+
+    assert_eq!(client.withdraw(&owner), goal);
+}
+
+// Test 63: Arbiter resolves toward refund
+#[test]
+fn test_dispute_resolved_toward_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_disputable_campaign(&client, &env, &owner, goal, deadline, &token_address, &arbiter);
+    client.donate(&donor, &goal, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 10;
+    });
+
+    assert!(client.flag_dispute(&donor));
+    client.resolve_dispute(&arbiter, &false);
+
+    assert!(client.is_refund_only());
+    assert!(client.try_withdraw(&owner).is_err());
+    assert_eq!(client.refund(&donor), goal);
+    assert_eq!(token.balance(&donor), 200_000_000);
+}
+
+// Test 64: Only the arbiter can resolve
+#[test]
+#[should_panic(expected = "Only arbiter can resolve dispute")]
+fn test_resolve_dispute_not_arbiter() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_disputable_campaign(&client, &env, &owner, goal, deadline, &token_address, &arbiter);
+    client.donate(&donor, &goal, &None);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 10;
+    });
+
+    client.flag_dispute(&donor);
+    client.resolve_dispute(&owner, &true);
+}
+
+// Test 65: Dispute window requires an arbiter
+#[test]
+#[should_panic(expected = "Dispute window requires an arbiter")]
+fn test_initialize_dispute_without_arbiter() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    let title = String::from_str(&env, "No Arbiter");
+    let description = String::from_str(&env, "Invalid dispute config");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        dispute_window: 500,
+        dispute_threshold_bps: 3_000,
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &Vec::new(&env), &config);
+}
+
+// 🔧 UPGRADE & MIGRATION TESTS
+
+// Release build of this contract, `make test` in the workspace root builds it before testing
+const CROWDFUNDING_WASM: &[u8] = include_bytes!("../../../target/wasm32v1-none/release/crowdfunding.wasm");
+
+// Test 66: Migrate campaign stored with the v1 layout
+#[test]
+fn test_migrate_v1_layout() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&contract_id, &50_000_000i128);
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Rewrite storage the way v1 laid it out
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        let mut donations: Map<Address, i128> = Map::new(&env);
+        donations.set(donor1.clone(), 30_000_000);
+        donations.set(donor2.clone(), 20_000_000);
+        storage.set(&DONATIONS, &donations);
+
+        let history = vec![
+            &env,
+            LegacyDonationRecord { donor: donor1.clone(), amount: 10_000_000, timestamp: 1 },
+            LegacyDonationRecord { donor: donor2.clone(), amount: 20_000_000, timestamp: 2 },
+            LegacyDonationRecord { donor: donor1.clone(), amount: 20_000_000, timestamp: 3 },
+        ];
+        storage.set(&DONATION_HISTORY, &history);
+        storage.set(&TOTAL_RAISED, &50_000_000i128);
+
+        for key in [SCHEMA_VERSION, PAYOUT_SPLITS, CAMPAIGN_CONFIG, CLAIMED, DONOR_COUNT, DONATED, TOP_DONORS, TOP_DONATIONS] {
+            storage.remove(&key);
+        }
+    });
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.migrate(), 2);
+
+    // Donations moved to per-donor entries
+    assert_eq!(client.get_schema_version(), 2);
+    assert_eq!(client.get_donation(&donor1), 30_000_000);
+    assert_eq!(client.get_donation(&donor2), 20_000_000);
+    assert_eq!(client.get_donor_count(), 2);
+    assert_eq!(client.get_top_donors(&10).get(0).unwrap().donor, donor1);
+    assert_eq!(client.get_largest_donation().unwrap().id, 1);
+
+    // History records got sequence ids
+    let history = client.get_donation_history(&10, &0);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().id, 2);
+    assert_eq!(history.get(0).unwrap().donor, donor1);
+
+    // Newer settings got defaults
+    assert_eq!(client.get_splits().get(0).unwrap().recipient, owner);
+    assert_eq!(client.get_claimed_amount(), 0);
+
+    // Running again is a no-op
+    assert_eq!(client.migrate(), 2);
+    assert_eq!(client.get_donation(&donor1), 30_000_000);
+
+    // Migrated campaign still refunds after failure
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    assert_eq!(client.refund(&donor1), 30_000_000);
+    assert_eq!(client.get_donor_count(), 1);
+    assert_eq!(client.get_top_donors(&10).get(0).unwrap().donor, donor2);
+}
+
+// Test 67: Guardian upgrades a live campaign, donations, balances and schema survive the code swap
+#[test]
+fn test_upgrade_live_campaign() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &200_000_000i128);
+    token_client.mint(&donor2, &200_000_000i128);
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+    client.donate(&donor1, &30_000_000, &None);
+    client.donate(&donor2, &10_000_000, &None);
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    client.upgrade(&guardian, &new_wasm_hash);
+
+    // Storage written by the running code is already current, migrate is a no-op
+    assert_eq!(client.get_schema_version(), 2);
+    assert_eq!(client.migrate(), 2);
+
+    assert_eq!(client.get_total_raised(), 40_000_000);
+    assert_eq!(client.get_donation(&donor1), 30_000_000);
+    assert_eq!(client.get_donation(&donor2), 10_000_000);
+    assert_eq!(client.get_donor_count(), 2);
+    assert_eq!(client.get_top_donors(&10).get(0).unwrap().donor, donor1);
+    assert_eq!(client.get_config().guardian, Some(guardian));
+    assert_eq!(token.balance(&contract_id), 40_000_000);
+
+    // Campaign keeps working: reaches goal and pays out everything held
+    client.donate(&donor2, &60_000_000, &None);
+    assert_eq!(client.get_donation(&donor2), 70_000_000);
+    assert_eq!(client.get_donor_count(), 2);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    assert_eq!(client.withdraw(&owner), goal);
+    assert_eq!(token.balance(&owner), goal);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(token.balance(&donor1), 170_000_000);
+    assert_eq!(token.balance(&donor2), 130_000_000);
+}
+
+// Test 68: Only the guardian can upgrade
+#[test]
+#[should_panic(expected = "Only guardian can perform this action")]
+fn test_upgrade_not_guardian() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, _) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    client.upgrade(&owner, &new_wasm_hash);
+}
+
+// Test 69: Owner upgrades a campaign created without a guardian
+#[test]
+fn test_upgrade_without_guardian_by_owner() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+    client.donate(&donor, &10_000_000, &None);
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    client.upgrade(&owner, &new_wasm_hash);
+
+    assert_eq!(client.get_donation(&donor), 10_000_000);
+    assert_eq!(client.migrate(), 2);
+}
+
+// Test 70: Without a guardian, only the owner can upgrade
+#[test]
+#[should_panic(expected = "Only owner can upgrade a campaign without guardian")]
+fn test_upgrade_without_guardian_not_owner() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, _) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    client.upgrade(&attacker, &new_wasm_hash);
+}
+
+// 🗓️ SCHEDULED LAUNCH TESTS
+
+// Test 71: Campaign moves from Upcoming to Active to Ended
+#[test]
+fn test_scheduled_start_status() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let start_time = env.ledger().timestamp() + 500;
+    let deadline = start_time + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    let title = String::from_str(&env, "Scheduled Campaign");
+    let description = String::from_str(&env, "Launches later");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        start_time: Some(start_time),
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &Vec::new(&env), &config);
+
+    assert_eq!(client.get_metadata().start_time, Some(start_time));
+    assert_eq!(client.get_status(), CampaignStatus::Upcoming);
+    assert!(client.is_upcoming());
+    assert!(!client.is_ended());
+
+    // Donations rejected before launch
+    assert!(client.try_donate(&donor, &10_000_000, &None).is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = start_time;
+    });
+    assert_eq!(client.get_status(), CampaignStatus::Active);
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_total_raised(), 10_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    assert_eq!(client.get_status(), CampaignStatus::Ended);
+}
+
+// Test 72: Donate before start time panics
+#[test]
+#[should_panic(expected = "Campaign has not started")]
+fn test_donate_before_start() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let start_time = env.ledger().timestamp() + 500;
+    let deadline = start_time + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    let title = String::from_str(&env, "Scheduled Campaign");
+    let description = String::from_str(&env, "Launches later");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        start_time: Some(start_time),
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &Vec::new(&env), &config);
+
+    client.donate(&donor, &10_000_000, &None);
+}
+
+// Test 73: Start time must be before deadline
+#[test]
+#[should_panic(expected = "Start time must be before deadline")]
+fn test_initialize_start_after_deadline() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    let title = String::from_str(&env, "Bad Schedule");
+    let description = String::from_str(&env, "Starts after it ends");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        start_time: Some(deadline),
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &Vec::new(&env), &config);
+}
+
+// ⏱️ ANTI-SNIPING TESTS
+
+// Helper function to initialize campaign with deadline auto-extension
+fn init_extending_campaign(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    deadline: u64,
+    xlm_token: &Address,
+) {
+    let title = String::from_str(env, "Charity Drive");
+    let description = String::from_str(env, "Late donations extend the deadline");
+    let category = symbol_short!("charity");
+    let config = CampaignConfig {
+        extension_window: 100,
+        extension_duration: 60,
+        max_extension: 150,
+        ..default_config()
+    };
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, xlm_token), &Vec::new(env), &config);
+}
+
+// Test 74: Late donations extend deadline up to the max
+#[test]
+fn test_late_donation_extends_deadline() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &200_000_000i128);
+
+    init_extending_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Early donation does not extend
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_deadline(), deadline);
+
+    // Donation inside the window extends by 60s
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline - 50;
+    });
+    client.donate(&donor, &10_000_000, &None);
+
+    // Event emitted with new deadline
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let data: Map<Symbol, Val> = event.2.into_val(&env);
+    let new_deadline: u64 = data.get(Symbol::new(&env, "new_deadline")).unwrap().into_val(&env);
+    assert_eq!(new_deadline, deadline + 60);
+
+    assert_eq!(client.get_deadline(), deadline + 60);
+    assert_eq!(client.get_deadline_extension(), 60);
+
+    // Extended deadline is outside the window again
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_deadline(), deadline + 60);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 10;
+    });
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_deadline(), deadline + 120);
+
+    // Last extension is capped at max_extension (150)
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 70;
+    });
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_deadline(), deadline + 150);
+    client.donate(&donor, &10_000_000, &None);
+    assert_eq!(client.get_deadline(), deadline + 150);
+    assert_eq!(client.get_deadline_extension(), 150);
+
+    // Campaign still open past the original deadline
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 140;
+    });
+    assert!(!client.is_ended());
+    client.donate(&donor, &10_000_000, &None);
+}
+
+// Test 75: Extension window requires extension settings
+#[test]
+#[should_panic(expected = "Extension window requires extension duration and max extension")]
+fn test_initialize_extension_without_duration() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+    let xlm_token_address =
+        Address::from_string(&soroban_sdk::String::from_str(&env, XLM_CONTRACT_TESTNET));
+
+    env.mock_all_auths();
+
+    let title = String::from_str(&env, "Bad Extension");
+    let description = String::from_str(&env, "No extension duration");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        extension_window: 100,
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token_address), &Vec::new(&env), &config);
+}
+
+// 🔄 SWAP DONATION TESTS
+
+// Mock AMM router with a fixed rate (amount_out = amount_in * num / den)
+// Does not enforce min_out itself so the campaign-side guard can be tested
+#[contract]
+pub struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn set_rate(env: Env, num: i128, den: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &(num, den));
+    }
+
+    pub fn swap_exact_in(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        _min_out: i128,
+        to: Address,
+    ) -> i128 {
+        let (num, den): (i128, i128) = env.storage().instance().get(&symbol_short!("rate")).unwrap();
+        let amount_out = amount_in * num / den;
+
+        let router = env.current_contract_address();
+        token::Client::new(&env, &token_in).transfer(&from, &router, &amount_in);
+        token::Client::new(&env, &token_out).transfer(&router, &to, &amount_out);
+
+        amount_out
+    }
+}
+
+// Helper function to set up a router (funded with campaign token) and a campaign using it
+// rate = (num, den), see MockRouter
+// Returns (router client, input token client)
+fn init_swap_campaign<'a>(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    deadline: u64,
+    xlm_token: &token::StellarAssetClient,
+    rate: (i128, i128),
+) -> (MockRouterClient<'a>, token::StellarAssetClient<'a>) {
+    let router_id = env.register(MockRouter, ());
+    let router = MockRouterClient::new(env, &router_id);
+    router.set_rate(&rate.0, &rate.1);
+    xlm_token.mint(&router_id, &1_000_000_000i128);
+
+    let (_, token_in) = create_token_contract(env, owner);
+
+    let title = String::from_str(env, "Swap Campaign");
+    let description = String::from_str(env, "Accepts any token");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        router: Some(router_id),
+        ..default_config()
+    };
+    client.initialize(owner, &campaign_params(&title, &description, goal, deadline, &category, &xlm_token.address), &Vec::new(env), &config);
+
+    (router, token_in)
+}
+
+// Test 76: Donate with another token is credited with the swap output
+#[test]
+fn test_donate_with_swap() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (_, xlm_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    // 1 input token = 2 campaign tokens
+    let (router, token_in) = init_swap_campaign(&client, &env, &owner, goal, deadline, &xlm_client, (2, 1));
+    token_in.mint(&donor, &50_000_000i128);
+
+    let credited = client.donate_with_swap(&donor, &token_in.address, &10_000_000, &15_000_000, &Some(referrer.clone()));
+
+    assert_eq!(credited, 20_000_000);
+    assert_eq!(client.get_total_raised(), 20_000_000);
+    assert_eq!(client.get_donation(&donor), 20_000_000);
+    assert_eq!(client.get_referral_total(&referrer), 20_000_000);
+
+    let xlm = token::Client::new(&env, &xlm_client.address);
+    let input = token::Client::new(&env, &token_in.address);
+    assert_eq!(xlm.balance(&contract_id), 20_000_000);
+    assert_eq!(input.balance(&donor), 40_000_000);
+    assert_eq!(input.balance(&router.address), 10_000_000);
+}
+
+// Test 77: Swap output below min_out reverts
+#[test]
+#[should_panic(expected = "Swap output below minimum")]
+fn test_donate_with_swap_below_min_out() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (_, xlm_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    // 2 input tokens = 1 campaign token
+    let (_, token_in) = init_swap_campaign(&client, &env, &owner, goal, deadline, &xlm_client, (1, 2));
+    token_in.mint(&donor, &50_000_000i128);
+
+    client.donate_with_swap(&donor, &token_in.address, &10_000_000, &6_000_000, &None);
+}
+
+// Test 78: donate_with_swap requires a configured router
+#[test]
+#[should_panic(expected = "Swap router not configured")]
+fn test_donate_with_swap_no_router() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, _) = create_token_contract(&env, &owner);
+    let (input_address, _) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+    client.donate_with_swap(&donor, &input_address, &10_000_000, &5_000_000, &None);
+}
+
+// ✍️ OFF-CHAIN PLEDGE TESTS
+
+// Helper function to sign a pledge the way the web app does (ed25519 over pledge XDR)
+fn sign_pledge(env: &Env, signing_key: &ed25519_dalek::SigningKey, pledge: &Pledge) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+
+    let message = pledge.clone().to_xdr(env);
+    let mut buf = [0u8; 1024];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &signing_key.sign(&buf[..len]).to_bytes())
+}
+
+// Helper function to create a donor pledge key and register it on the campaign
+fn register_pledge_key(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    donor: &Address,
+    seed: u8,
+) -> ed25519_dalek::SigningKey {
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    client.set_pledge_key(donor, &public_key);
+    signing_key
+}
+
+// Test 79: Relayer redeems a signed pledge, nonce cannot be replayed
+#[test]
+fn test_redeem_pledge() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    let signing_key = register_pledge_key(&client, &env, &donor, 7);
+    token.approve(&donor, &contract_id, &50_000_000, &1000);
+
+    let pledge = Pledge {
+        campaign: contract_id.clone(),
+        donor: donor.clone(),
+        amount: 30_000_000,
+        expiry: deadline,
+        nonce: 1,
+    };
+    let signature = sign_pledge(&env, &signing_key, &pledge);
+
+    // Relayer submits without donor auth
+    env.set_auths(&[]);
+    client.redeem_pledge(&pledge, &signature);
+
+    assert_eq!(client.get_donation(&donor), 30_000_000);
+    assert_eq!(client.get_total_raised(), 30_000_000);
+    assert_eq!(token.balance(&donor), 70_000_000);
+    assert!(client.is_pledge_nonce_used(&donor, &1));
+
+    // Same pledge cannot be redeemed twice
+    assert!(client.try_redeem_pledge(&pledge, &signature).is_err());
+    assert_eq!(client.get_total_raised(), 30_000_000);
+}
+
+// Test 80: Pledge signed by a different key is rejected
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_redeem_pledge_wrong_signer() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    register_pledge_key(&client, &env, &donor, 7);
+    token.approve(&donor, &contract_id, &50_000_000, &1000);
+
+    let pledge = Pledge {
+        campaign: contract_id.clone(),
+        donor: donor.clone(),
+        amount: 30_000_000,
+        expiry: deadline,
+        nonce: 1,
+    };
+    let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+    let signature = sign_pledge(&env, &attacker_key, &pledge);
+
+    client.redeem_pledge(&pledge, &signature);
+}
+
+// Test 81: Expired pledge is rejected
+#[test]
+#[should_panic(expected = "Pledge expired")]
+fn test_redeem_pledge_expired() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    let signing_key = register_pledge_key(&client, &env, &donor, 7);
+    let pledge = Pledge {
+        campaign: contract_id.clone(),
+        donor: donor.clone(),
+        amount: 30_000_000,
+        expiry: env.ledger().timestamp() + 100,
+        nonce: 1,
+    };
+    let signature = sign_pledge(&env, &signing_key, &pledge);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 101;
+    });
+    client.redeem_pledge(&pledge, &signature);
+}
+
+// 🪙 WORKSHOP TOKEN INTEGRATION TESTS
+
+// Test 82: Full campaign lifecycle using the workshop TokenContract (SEP-41)
+#[test]
+fn test_full_campaign_with_token_contract() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    env.mock_all_auths();
+
+    // Issue workshop token and hand it out to donors
+    let token_address = env.register(::token::TokenContract, ());
+    let token_admin = ::token::TokenContractClient::new(&env, &token_address);
+    token_admin.initialize(
+        &issuer,
+        &::token::TokenParams {
+            name: String::from_str(&env, "Workshop Token"),
+            symbol: String::from_str(&env, "WST"),
+            decimals: 7,
+            clawback_enabled: false,
+        },
+        &1_000_000_000i128,
+        &None,
+        &None,
+    );
+    let token = token::Client::new(&env, &token_address);
+    token.transfer(&issuer, &donor1, &200_000_000);
+    token.transfer(&issuer, &donor2, &200_000_000);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Direct donation and allowance-based subscription
+    client.donate(&donor1, &60_000_000, &None);
+    token.approve(&donor2, &contract_id, &40_000_000, &1000);
+    client.subscribe(&donor2, &40_000_000, &1000);
+    client.collect_subscription(&donor2);
+
+    assert!(client.is_goal_reached());
+    assert_eq!(token.balance(&contract_id), goal);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    assert_eq!(client.withdraw(&owner), goal);
+    assert_eq!(token.balance(&owner), goal);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    // Supply conserved across the whole campaign
+    let holders = [issuer, donor1, donor2, owner, contract_id];
+    let total: i128 = holders.iter().map(|holder| token.balance(holder)).sum();
+    assert_eq!(total, token_admin.get_total_supply());
+}
+
+// 🧮 OVERFLOW & ACCOUNTING TESTS
+
+// Test 83: Deadline extension past u64::MAX fails with typed error
+#[test]
+fn test_late_donation_extension_overflow() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = u64::MAX - 50;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+    init_extending_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline - 10;
+    });
+
+    let result = client.try_donate(&donor, &10_000_000, &None);
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+    assert_eq!(client.get_deadline(), deadline);
+}
+
+// Test 84: Pro-rata refund in refund-only mode keeps the average donation accurate
+#[test]
+fn test_average_donation_after_pro_rata_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &100_000_000i128);
+    token_client.mint(&donor2, &100_000_000i128);
+
+    init_guarded_campaign(&client, &env, &owner, goal, deadline, &token_address, &guardian);
+    client.donate(&donor1, &60_000_000, &None);
+    client.donate(&donor2, &20_000_000, &None);
+    assert_eq!(client.get_average_donation(), 40_000_000);
+
+    client.force_refund_only(&guardian);
+    assert_eq!(client.refund(&donor2), 20_000_000);
+
+    // Total raised stays the pro-rata base, average only counts donor1
+    assert_eq!(client.get_total_raised(), 80_000_000);
+    assert_eq!(client.get_donor_count(), 1);
+    assert_eq!(client.get_average_donation(), 60_000_000);
+}
+
+// Test 85: Largest donation moves on when its donor is refunded
+#[test]
+fn test_largest_donation_after_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let donor3 = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor1, &100_000_000i128);
+    token_client.mint(&donor2, &100_000_000i128);
+    token_client.mint(&donor3, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+    client.donate(&donor1, &30_000_000, &None);
+    client.donate(&donor2, &50_000_000, &None);
+    client.donate(&donor2, &5_000_000, &None);
+    client.donate(&donor3, &10_000_000, &None);
+    assert_eq!(client.get_largest_donation().unwrap().donor, donor2);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    client.refund(&donor2);
+
+    let largest = client.get_largest_donation().unwrap();
+    assert_eq!(largest.donor, donor1);
+    assert_eq!(largest.amount, 30_000_000);
+    assert_eq!(largest.id, 0);
+
+    client.refund(&donor1);
+    client.refund(&donor3);
+    assert!(client.get_largest_donation().is_none());
+}
+
+// Test 86: Subscription whose next due date would overflow fails with typed error
+#[test]
+fn test_subscription_next_due_overflow() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let monthly = 5_000_000i128;
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000;
+    });
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    token.approve(&donor, &contract_id, &monthly, &1000);
+    client.subscribe(&donor, &monthly, &u64::MAX);
+
+    let result = client.try_collect_subscription(&donor);
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+    assert_eq!(client.get_total_raised(), 0);
+}
+
+// Test 87: Donor count that would overflow fails with typed error
+#[test]
+fn test_donor_count_overflow() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DONOR_COUNT, &u32::MAX);
+    });
+
+    let result = client.try_donate(&donor, &10_000_000, &None);
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+    assert_eq!(client.get_donor_count(), u32::MAX);
+}
+
+// Test 88: Refund of a top donor backfills the board from cumulative totals, not single donations
+#[test]
+fn test_top_donors_backfill_after_refund() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let goal = 1_000_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+
+    env.mock_all_auths();
+
+    init_campaign(&client, &env, &owner, goal, deadline, &token_address);
+
+    // Ten donors fill the leaderboard with one 20M donation each
+    let mut donors: Vec<Address> = Vec::new(&env);
+    for _ in 0..10 {
+        let donor = Address::generate(&env);
+        token_client.mint(&donor, &20_000_000i128);
+        client.donate(&donor, &20_000_000, &None);
+        donors.push_back(donor);
+    }
+
+    // Donor K gives five 2M donations, none of them among the largest single donations
+    let donor_k = Address::generate(&env);
+    token_client.mint(&donor_k, &10_000_000i128);
+    for _ in 0..5 {
+        client.donate(&donor_k, &2_000_000, &None);
+    }
+
+    let top_donors = client.get_top_donors(&10);
+    assert_eq!(top_donors.len(), 10);
+    assert!(!top_donors.iter().any(|entry| entry.donor == donor_k));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    let refunded = donors.get(0).unwrap();
+    client.refund(&refunded);
+
+    // K takes the freed slot with its 10M total
+    let top_donors = client.get_top_donors(&10);
+    assert_eq!(top_donors.len(), 10);
+    assert!(!top_donors.iter().any(|entry| entry.donor == refunded));
+    let last = top_donors.get(9).unwrap();
+    assert_eq!(last.donor, donor_k);
+    assert_eq!(last.total, 10_000_000);
+
+    // Largest donation falls back to K once every 20M donor is refunded
+    for donor in donors.iter().skip(1) {
+        client.refund(&donor);
+    }
+    let largest = client.get_largest_donation().unwrap();
+    assert_eq!(largest.donor, donor_k);
+    assert_eq!(largest.amount, 2_000_000);
+    let top_donors = client.get_top_donors(&10);
+    assert_eq!(top_donors.len(), 1);
+    assert_eq!(top_donors.get(0).unwrap().donor, donor_k);
+}
+
+// Receipt contract that rejects every call
+#[contract]
+pub struct BrokenReceipt;
+
+#[contractimpl]
+impl BrokenReceipt {
+    pub fn mint(_env: Env, _campaign: Address, _donor: Address, _amount: i128) -> u64 {
+        panic!("Receipt unavailable");
+    }
+
+    pub fn burn(_env: Env, _campaign: Address, _donor: Address) {
+        panic!("Receipt unavailable");
+    }
+}
+
+// Test 89: Failing receipt contract blocks neither donations nor refunds
+#[test]
+fn test_broken_receipt_does_not_block_funds() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let receipt_id = env.register(BrokenReceipt, ());
+
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    let (token_address, token_client) = create_token_contract(&env, &owner);
+    let token = token::Client::new(&env, &token_address);
+
+    env.mock_all_auths();
+
+    token_client.mint(&donor, &100_000_000i128);
+
+    let title = String::from_str(&env, "Receipt Campaign");
+    let description = String::from_str(&env, "Receipt contract is down");
+    let category = symbol_short!("tech");
+    let config = CampaignConfig {
+        receipt: Some(receipt_id),
+        ..default_config()
+    };
+    client.initialize(&owner, &campaign_params(&title, &description, goal, deadline, &category, &token_address), &Vec::new(&env), &config);
+
+    client.donate(&donor, &30_000_000, &None);
+    assert_eq!(client.get_donation(&donor), 30_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    assert_eq!(client.refund(&donor), 30_000_000);
+    assert_eq!(token.balance(&donor), 100_000_000);
+}
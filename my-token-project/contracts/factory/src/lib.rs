@@ -1,9 +1,72 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec, symbol_short};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec, symbol_short};
 
 // Storage keys
 const CAMPAIGN_COUNTER: Symbol = symbol_short!("counter");
 const CAMPAIGNS: Symbol = symbol_short!("campaigns");
+const ADMIN: Symbol = symbol_short!("admin");
+const GUARDIAN: Symbol = symbol_short!("guardian");
+
+// Storage schema version (v1 = before versioning)
+const SCHEMA_VERSION: Symbol = symbol_short!("schema_v");
+const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+// Payout recipient share, mirrors crowdfunding::PayoutSplit
+#[contracttype]
+#[derive(Clone)]
+pub struct PayoutSplit {
+    pub recipient: Address,
+    pub bps: u32,
+}
+
+// Core campaign parameters, mirrors crowdfunding::CampaignParams
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignParams {
+    pub title: String,
+    pub description: String,
+    pub goal: i128,
+    pub deadline: u64,
+    pub category: Symbol,
+    pub xlm_token: Address,
+}
+
+// Optional campaign settings, mirrors crowdfunding::CampaignConfig
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignConfig {
+    pub vesting_duration: u64,
+    pub receipt: Option<Address>,
+    pub guardian: Option<Address>,
+    pub dispute_window: u64,
+    pub dispute_threshold_bps: u32,
+    pub arbiter: Option<Address>,
+    pub start_time: Option<u64>,
+    pub extension_window: u64,
+    pub extension_duration: u64,
+    pub max_extension: u64,
+    pub router: Option<Address>,
+}
+
+// Emitted when factory admin replaces the guardian
+#[contractevent]
+#[derive(Clone)]
+pub struct GuardianChanged {
+    pub old_guardian: Option<Address>,
+    pub new_guardian: Address,
+}
+
+// Campaign info as stored by schema v1 (no start time)
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyCampaignInfo {
+    pub id: u64,
+    pub address: Address,
+    pub title: String,
+    pub category: Symbol,
+    pub owner: Address,
+    pub created_at: u64,
+}
 
 // Campaign info stored in factory for efficient listing
 #[contracttype]
@@ -15,6 +78,7 @@ pub struct CampaignInfo {
     pub category: Symbol,
     pub owner: Address,
     pub created_at: u64,
+    pub start_time: Option<u64>,
 }
 
 #[contract]
@@ -23,31 +87,168 @@ pub struct FactoryContract;
 #[contractimpl]
 impl FactoryContract {
 
+    /// Set factory admin and platform guardian at deployment
+    /// Runs once as part of the deploy, so nobody can claim the factory afterwards
+    pub fn __constructor(env: Env, admin: Address, guardian: Address) {
+        env.storage().instance().set(&ADMIN, &admin);
+        env.storage().instance().set(&GUARDIAN, &guardian);
+        env.storage().instance().set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+    }
+
+    /// Replace platform guardian - Admin only
+    pub fn set_guardian(env: Env, admin: Address, new_guardian: Address) {
+        admin.require_auth();
+
+        let factory_admin: Option<Address> = env.storage().instance().get(&ADMIN);
+        if factory_admin != Some(admin) {
+            panic!("Only admin can set guardian");
+        }
+
+        let old_guardian: Option<Address> = env.storage().instance().get(&GUARDIAN);
+        env.storage().instance().set(&GUARDIAN, &new_guardian);
+
+        GuardianChanged {
+            old_guardian,
+            new_guardian,
+        }
+        .publish(&env);
+    }
+
+    /// Get factory admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ADMIN)
+    }
+
+    /// Get platform guardian
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&GUARDIAN)
+    }
+
+    /// Pause donate and withdraw on a campaign - Guardian only
+    pub fn pause_campaign(env: Env, guardian: Address, id: u64) {
+        Self::invoke_as_guardian(&env, &guardian, id, "pause");
+    }
+
+    /// Unpause a campaign - Guardian only
+    pub fn unpause_campaign(env: Env, guardian: Address, id: u64) {
+        Self::invoke_as_guardian(&env, &guardian, id, "unpause");
+    }
+
+    /// Force a campaign into refund-only mode - Guardian only
+    pub fn force_refund_campaign(env: Env, guardian: Address, id: u64) {
+        Self::invoke_as_guardian(&env, &guardian, id, "force_refund_only");
+    }
+
+    // Verify caller is factory admin
+    fn require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+
+        let factory_admin: Option<Address> = env.storage().instance().get(&ADMIN);
+        if factory_admin != Some(admin.clone()) {
+            panic!("Only admin can upgrade");
+        }
+    }
+
+    /// Upgrade factory contract code - Admin only
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Get storage schema version (1 = layout before versioning)
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION).unwrap_or(1)
+    }
+
+    /// Migrate storage from older layouts to the current schema version
+    /// v1 -> v2: campaign info gets start time (None, campaigns were live at creation)
+    /// Returns the schema version after migration
+    pub fn migrate(env: Env) -> u32 {
+        let version = Self::get_schema_version(env.clone());
+
+        if version < 2 {
+            let legacy_campaigns: Vec<LegacyCampaignInfo> = env
+                .storage()
+                .instance()
+                .get(&CAMPAIGNS)
+                .unwrap_or(Vec::new(&env));
+            let mut campaigns: Vec<CampaignInfo> = Vec::new(&env);
+            for legacy in legacy_campaigns.iter() {
+                campaigns.push_back(CampaignInfo {
+                    id: legacy.id,
+                    address: legacy.address,
+                    title: legacy.title,
+                    category: legacy.category,
+                    owner: legacy.owner,
+                    created_at: legacy.created_at,
+                    start_time: None,
+                });
+            }
+            env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        }
+
+        env.storage().instance().set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+        CURRENT_SCHEMA_VERSION
+    }
+
+    /// Upgrade a campaign contract code - Admin only
+    /// Factory is the campaign guardian, so it authorizes the upgrade on the campaign
+    pub fn upgrade_campaign(env: Env, admin: Address, id: u64, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+
+        let campaign_address = Self::get_campaign(env.clone(), id);
+        let args = vec![
+            &env,
+            env.current_contract_address().into_val(&env),
+            new_wasm_hash.into_val(&env),
+        ];
+        env.invoke_contract::<()>(&campaign_address, &Symbol::new(&env, "upgrade"), args);
+    }
+
+    // Verify guardian, then call guardian function on campaign
+    // Factory is the guardian recorded in every campaign it deploys
+    fn invoke_as_guardian(env: &Env, guardian: &Address, id: u64, function: &str) {
+        guardian.require_auth();
+
+        let platform_guardian: Option<Address> = env.storage().instance().get(&GUARDIAN);
+        if platform_guardian != Some(guardian.clone()) {
+            panic!("Only guardian can perform this action");
+        }
+
+        let campaign_address = Self::get_campaign(env.clone(), id);
+        let args = vec![env, env.current_contract_address().into_val(env)];
+        env.invoke_contract::<()>(&campaign_address, &Symbol::new(env, function), args);
+    }
+
     /// Create a new campaign contract
+    /// Factory is set as the campaign guardian (guardian actions go through factory)
     /// Returns campaign ID
     pub fn create_campaign(
         env: Env,
         owner: Address,
-        title: String,
-        description: String,
-        goal: i128,
-        deadline: u64,
-        category: Symbol,
-        xlm_token: Address,
+        params: CampaignParams,
+        splits: Vec<PayoutSplit>,
+        config: CampaignConfig,
         campaign_wasm_hash: BytesN<32>, // WASM hash of campaign contract
     ) -> u64 {
         // Require owner authorization
         owner.require_auth();
 
         // Validate inputs
-        if goal < 100_000_000 {
+        if params.goal < 100_000_000 {
             panic!("Goal must be at least 10 XLM");
         }
 
-        if deadline <= env.ledger().timestamp() {
+        if params.deadline <= env.ledger().timestamp() {
             panic!("Deadline must be in the future");
         }
 
+        // Factory acts as guardian for all its campaigns
+        let mut config = config;
+        config.guardian = Some(env.current_contract_address());
+        let start_time = config.start_time;
+
         // Get or initialize counter
         let mut counter: u64 = env.storage().instance().get(&CAMPAIGN_COUNTER).unwrap_or(0);
 
@@ -62,12 +263,9 @@ impl FactoryContract {
         let init_args = vec![
             &env,
             owner.clone().into_val(&env),
-            title.clone().into_val(&env),
-            description.clone().into_val(&env),
-            goal.into_val(&env),
-            deadline.into_val(&env),
-            category.clone().into_val(&env),
-            xlm_token.clone().into_val(&env),
+            params.clone().into_val(&env),
+            splits.into_val(&env),
+            config.into_val(&env),
         ];
 
         env.invoke_contract::<()>(
@@ -80,10 +278,11 @@ impl FactoryContract {
         let campaign_info = CampaignInfo {
             id: counter,
             address: campaign_address.clone(),
-            title: title.clone(),
-            category: category.clone(),
+            title: params.title,
+            category: params.category,
             owner: owner.clone(),
             created_at: env.ledger().timestamp(),
+            start_time,
         };
 
         // Get campaigns vec or create new
@@ -122,6 +321,26 @@ impl FactoryContract {
             .unwrap_or(Vec::new(&env))
     }
 
+    /// Get campaigns that have not started yet (scheduled launch)
+    pub fn get_upcoming_campaigns(env: Env) -> Vec<CampaignInfo> {
+        let campaigns: Vec<CampaignInfo> = env
+            .storage()
+            .instance()
+            .get(&CAMPAIGNS)
+            .unwrap_or(Vec::new(&env));
+
+        let now = env.ledger().timestamp();
+        let mut upcoming = Vec::new(&env);
+
+        for campaign in campaigns.iter() {
+            if campaign.start_time.is_some_and(|start_time| now < start_time) {
+                upcoming.push_back(campaign);
+            }
+        }
+
+        upcoming
+    }
+
     /// Get campaigns by owner
     pub fn get_campaigns_by_owner(env: Env, owner: Address) -> Vec<u64> {
         let campaigns: Vec<CampaignInfo> = env
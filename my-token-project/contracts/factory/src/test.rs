@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, token, xdr, Env, TryFromVal, Val};
 
 // Basic tests for factory contract
 // Note: Full integration tests will be added after campaign contract update
@@ -9,7 +9,9 @@ use soroban_sdk::{testutils::Address as _, Env};
 #[test]
 fn test_get_campaign_count_initial() {
     let env = Env::default();
-    let contract_id = env.register(FactoryContract, ());
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
     let client = FactoryContractClient::new(&env, &contract_id);
 
     // Initial count should be 0
@@ -19,7 +21,9 @@ fn test_get_campaign_count_initial() {
 #[test]
 fn test_get_all_campaigns_initial() {
     let env = Env::default();
-    let contract_id = env.register(FactoryContract, ());
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
     let client = FactoryContractClient::new(&env, &contract_id);
 
     // Initial campaigns list should be empty
@@ -30,7 +34,9 @@ fn test_get_all_campaigns_initial() {
 #[test]
 fn test_get_campaigns_by_owner_empty() {
     let env = Env::default();
-    let contract_id = env.register(FactoryContract, ());
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
     let client = FactoryContractClient::new(&env, &contract_id);
 
     let owner = Address::generate(&env);
@@ -40,6 +46,58 @@ fn test_get_campaigns_by_owner_empty() {
     assert_eq!(owner_campaigns.len(), 0);
 }
 
+#[test]
+fn test_set_guardian() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    let new_guardian = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    // Admin and guardian set at deployment
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+    assert_eq!(client.get_guardian(), Some(guardian));
+
+    // Admin replaces guardian
+    client.set_guardian(&admin, &new_guardian);
+    assert_eq!(client.get_guardian(), Some(new_guardian));
+}
+
+#[test]
+#[should_panic(expected = "Only admin can set guardian")]
+fn test_set_guardian_not_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    let attacker = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    // Non-admin cannot replace guardian
+    client.set_guardian(&attacker, &attacker);
+}
+
+#[test]
+fn test_constructor_sets_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    // Admin is fixed at deployment, there is no initializer to front-run
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_guardian(), Some(guardian));
+    assert_eq!(client.get_schema_version(), 2);
+}
+
 // TODO: Add full integration tests after campaign contract is updated with metadata
 // These tests will include:
 // - test_create_campaign_success()
@@ -48,3 +106,244 @@ fn test_get_campaigns_by_owner_empty() {
 // - test_get_campaign_by_id()
 // - test_get_campaigns_by_owner()
 // - test_multiple_campaigns()
+
+#[test]
+#[should_panic(expected = "Only admin can upgrade")]
+fn test_upgrade_not_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    client.upgrade(&guardian, &BytesN::from_array(&env, &[0; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Only admin can upgrade")]
+fn test_upgrade_campaign_not_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    client.upgrade_campaign(&guardian, &0, &BytesN::from_array(&env, &[0; 32]));
+}
+
+// Release build of the campaign contract, `make test` in the workspace root builds it before testing
+mod crowdfunding {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/crowdfunding.wasm");
+}
+const CROWDFUNDING_WASM: &[u8] = crowdfunding::WASM;
+
+fn campaign_config(env: &Env, start_time: Option<u64>) -> CampaignConfig {
+    CampaignConfig {
+        vesting_duration: 0,
+        receipt: None,
+        guardian: None,
+        dispute_window: 0,
+        dispute_threshold_bps: 0,
+        arbiter: None,
+        start_time: start_time.map(|offset| env.ledger().timestamp() + offset),
+        extension_window: 0,
+        extension_duration: 0,
+        max_extension: 0,
+        router: None,
+    }
+}
+
+#[test]
+fn test_get_upcoming_campaigns() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let xlm_token = Address::generate(&env);
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    let params = CampaignParams {
+        title: String::from_str(&env, "Campaign"),
+        description: String::from_str(&env, "Factory campaign"),
+        goal,
+        deadline,
+        category: Symbol::new(&env, "tech"),
+        xlm_token,
+    };
+
+    // One live campaign, one launching later
+    client.create_campaign(&owner, &params, &Vec::new(&env), &campaign_config(&env, None), &wasm_hash);
+    let upcoming_id = client.create_campaign(&owner, &params, &Vec::new(&env), &campaign_config(&env, Some(500)), &wasm_hash);
+
+    let upcoming = client.get_upcoming_campaigns();
+    assert_eq!(upcoming.len(), 1);
+    assert_eq!(upcoming.get(0).unwrap().id, upcoming_id);
+
+    // No longer upcoming after launch
+    env.ledger().with_mut(|li| {
+        li.timestamp += 500;
+    });
+    assert_eq!(client.get_upcoming_campaigns().len(), 0);
+}
+
+#[test]
+fn test_migrate_v1_campaigns() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let campaign = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    // Campaign list the way v1 stored it (no start time)
+    env.as_contract(&contract_id, || {
+        let campaigns = vec![
+            &env,
+            LegacyCampaignInfo {
+                id: 0,
+                address: campaign.clone(),
+                title: String::from_str(&env, "Old Campaign"),
+                category: Symbol::new(&env, "tech"),
+                owner: owner.clone(),
+                created_at: 1,
+            },
+        ];
+        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        env.storage().instance().set(&CAMPAIGN_COUNTER, &1u64);
+        env.storage().instance().remove(&SCHEMA_VERSION);
+    });
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.migrate(), 2);
+
+    // Old entries decode with the new layout
+    let campaigns = client.get_all_campaigns();
+    assert_eq!(campaigns.len(), 1);
+    assert_eq!(campaigns.get(0).unwrap().address, campaign);
+    assert_eq!(campaigns.get(0).unwrap().start_time, None);
+    assert_eq!(client.get_campaign(&0), campaign);
+    assert_eq!(client.get_campaigns_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_upcoming_campaigns().len(), 0);
+}
+
+// Deploy a factory and one campaign through it, with a funded donor
+fn setup_campaign<'a>(env: &Env) -> (FactoryContractClient<'a>, crowdfunding::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let guardian = Address::generate(env);
+    let owner = Address::generate(env);
+    let donor = Address::generate(env);
+    let contract_id = env.register(FactoryContract, (&admin, &guardian));
+    let client = FactoryContractClient::new(env, &contract_id);
+
+    env.mock_all_auths();
+
+    let xlm_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    token::StellarAssetClient::new(env, &xlm_token).mint(&donor, &100_000_000i128);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    let params = CampaignParams {
+        title: String::from_str(env, "Campaign"),
+        description: String::from_str(env, "Factory campaign"),
+        goal: 100_000_000i128,
+        deadline: env.ledger().timestamp() + 10_000,
+        category: Symbol::new(env, "tech"),
+        xlm_token,
+    };
+    let id = client.create_campaign(&owner, &params, &Vec::new(env), &campaign_config(env, None), &wasm_hash);
+    let campaign = crowdfunding::Client::new(env, &client.get_campaign(&id));
+
+    (client, campaign, admin, guardian, donor)
+}
+
+#[test]
+fn test_pause_and_unpause_campaign() {
+    let env = Env::default();
+    let (client, campaign, _admin, guardian, donor) = setup_campaign(&env);
+
+    // Factory is the guardian recorded in the campaign
+    assert_eq!(campaign.get_config().guardian, Some(client.address.clone()));
+
+    client.pause_campaign(&guardian, &0);
+    assert!(campaign.is_paused());
+    assert!(campaign.try_donate(&donor, &10_000_000, &None).is_err());
+
+    client.unpause_campaign(&guardian, &0);
+    assert!(!campaign.is_paused());
+    campaign.donate(&donor, &10_000_000, &None);
+    assert_eq!(campaign.get_total_raised(), 10_000_000);
+}
+
+#[test]
+fn test_force_refund_campaign() {
+    let env = Env::default();
+    let (client, campaign, _admin, guardian, donor) = setup_campaign(&env);
+
+    campaign.donate(&donor, &10_000_000, &None);
+
+    client.force_refund_campaign(&guardian, &0);
+    assert!(campaign.is_refund_only());
+
+    // Donor gets funds back before the deadline
+    assert_eq!(campaign.refund(&donor), 10_000_000);
+    assert_eq!(campaign.get_donation(&donor), 0);
+}
+
+#[test]
+#[should_panic(expected = "Only guardian can perform this action")]
+fn test_pause_campaign_not_guardian() {
+    let env = Env::default();
+    let (client, _campaign, admin, _guardian, _donor) = setup_campaign(&env);
+
+    client.pause_campaign(&admin, &0);
+}
+
+#[test]
+fn test_upgrade_campaign() {
+    let env = Env::default();
+    let (client, campaign, admin, _guardian, donor) = setup_campaign(&env);
+
+    campaign.donate(&donor, &10_000_000, &None);
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(CROWDFUNDING_WASM);
+    client.upgrade_campaign(&admin, &0, &new_wasm_hash);
+
+    // Host records the executable update on the campaign (system event, not in events().all())
+    let campaign_address = xdr::ScAddress::from(&campaign.address);
+    let update_topic = xdr::ScVal::try_from_val(&env, &Symbol::new(&env, "executable_update").to_val()).unwrap();
+    let new_executable: Val = (Symbol::new(&env, "Wasm"), new_wasm_hash).into_val(&env);
+    let new_executable = xdr::ScVal::try_from_val(&env, &new_executable).unwrap();
+    let updated = env.host().get_events().unwrap().0.into_iter().any(|e| match e.event {
+        xdr::ContractEvent {
+            type_: xdr::ContractEventType::System,
+            contract_id: Some(contract_id),
+            body: xdr::ContractEventBody::V0(xdr::ContractEventV0 { topics, .. }),
+            ..
+        } => {
+            xdr::ScAddress::Contract(contract_id) == campaign_address
+                && topics.len() == 3
+                && topics[0] == update_topic
+                && topics[2] == new_executable
+        }
+        _ => false,
+    });
+    assert!(updated);
+
+    // Campaign state survives the upgrade
+    assert_eq!(campaign.get_total_raised(), 10_000_000);
+    assert_eq!(campaign.migrate(), campaign.get_schema_version());
+}
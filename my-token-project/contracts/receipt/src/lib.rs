@@ -0,0 +1,126 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+// Storage keys
+const NEXT_ID: Symbol = symbol_short!("next_id");
+
+// Persistent storage keys (one entry per receipt / donor)
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Receipt(u64),
+    ReceiptId(Address, Address),
+    Balance(Address),
+}
+
+// Proof-of-donation receipt (soulbound, tidak bisa di-transfer)
+#[contracttype]
+#[derive(Clone)]
+pub struct ReceiptMetadata {
+    pub id: u64,
+    pub campaign: Address,
+    pub donor: Address,
+    pub amount: i128,
+    pub minted_at: u64,
+    pub updated_at: u64,
+}
+
+#[contract]
+pub struct ReceiptContract;
+
+#[contractimpl]
+impl ReceiptContract {
+
+    /// Mint receipt for (campaign, donor), or add to its cumulative amount
+    /// Only the campaign contract itself can mint its receipts
+    /// Returns receipt ID
+    pub fn mint(env: Env, campaign: Address, donor: Address, amount: i128) -> u64 {
+        campaign.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be greater than 0");
+        }
+
+        let now = env.ledger().timestamp();
+        let id_key = DataKey::ReceiptId(campaign.clone(), donor.clone());
+
+        // Existing receipt: update cumulative amount
+        if let Some(id) = env.storage().persistent().get::<_, u64>(&id_key) {
+            let mut receipt: ReceiptMetadata = env.storage().persistent().get(&DataKey::Receipt(id)).unwrap();
+            receipt.amount += amount;
+            receipt.updated_at = now;
+            env.storage().persistent().set(&DataKey::Receipt(id), &receipt);
+            return id;
+        }
+
+        // New receipt
+        let id: u64 = env.storage().instance().get(&NEXT_ID).unwrap_or(0);
+        let receipt = ReceiptMetadata {
+            id,
+            campaign,
+            donor: donor.clone(),
+            amount,
+            minted_at: now,
+            updated_at: now,
+        };
+
+        env.storage().persistent().set(&DataKey::Receipt(id), &receipt);
+        env.storage().persistent().set(&id_key, &id);
+
+        let balance = Self::balance(env.clone(), donor.clone());
+        env.storage().persistent().set(&DataKey::Balance(donor), &(balance + 1));
+
+        env.storage().instance().set(&NEXT_ID, &(id + 1));
+
+        id
+    }
+
+    /// Burn receipt for (campaign, donor), e.g. on refund
+    /// Only the campaign contract itself can burn its receipts
+    pub fn burn(env: Env, campaign: Address, donor: Address) {
+        campaign.require_auth();
+
+        let id_key = DataKey::ReceiptId(campaign, donor.clone());
+        let id: u64 = match env.storage().persistent().get(&id_key) {
+            Some(id) => id,
+            None => panic!("Receipt not found"),
+        };
+
+        env.storage().persistent().remove(&DataKey::Receipt(id));
+        env.storage().persistent().remove(&id_key);
+
+        let balance = Self::balance(env.clone(), donor.clone());
+        env.storage().persistent().set(&DataKey::Balance(donor), &(balance - 1));
+    }
+
+    /// Get owner (donor) of receipt
+    pub fn owner_of(env: Env, id: u64) -> Address {
+        Self::metadata(env, id).donor
+    }
+
+    /// Get number of receipts held by donor
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        env.storage().persistent().get(&DataKey::Balance(owner)).unwrap_or(0)
+    }
+
+    /// Get receipt metadata
+    pub fn metadata(env: Env, id: u64) -> ReceiptMetadata {
+        match env.storage().persistent().get(&DataKey::Receipt(id)) {
+            Some(receipt) => receipt,
+            None => panic!("Receipt not found"),
+        }
+    }
+
+    /// Get receipt ID for (campaign, donor), None jika belum ada
+    pub fn get_receipt_id(env: Env, campaign: Address, donor: Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::ReceiptId(campaign, donor))
+    }
+
+    /// Get total receipts ever minted
+    pub fn get_receipt_count(env: Env) -> u64 {
+        env.storage().instance().get(&NEXT_ID).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_mint_receipt() {
+    let env = Env::default();
+    let contract_id = env.register(ReceiptContract, ());
+    let client = ReceiptContractClient::new(&env, &contract_id);
+
+    let campaign = Address::generate(&env);
+    let donor = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let id = client.mint(&campaign, &donor, &10_000_000);
+
+    // Verify receipt
+    assert_eq!(id, 0);
+    assert_eq!(client.owner_of(&id), donor);
+    assert_eq!(client.balance(&donor), 1);
+    assert_eq!(client.get_receipt_count(), 1);
+
+    let receipt = client.metadata(&id);
+    assert_eq!(receipt.campaign, campaign);
+    assert_eq!(receipt.amount, 10_000_000);
+}
+
+#[test]
+fn test_mint_updates_cumulative_amount() {
+    let env = Env::default();
+    let contract_id = env.register(ReceiptContract, ());
+    let client = ReceiptContractClient::new(&env, &contract_id);
+
+    let campaign1 = Address::generate(&env);
+    let campaign2 = Address::generate(&env);
+    let donor = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    // Same (campaign, donor) reuses the receipt
+    let id = client.mint(&campaign1, &donor, &10_000_000);
+    assert_eq!(client.mint(&campaign1, &donor, &5_000_000), id);
+    assert_eq!(client.metadata(&id).amount, 15_000_000);
+    assert_eq!(client.balance(&donor), 1);
+
+    // Different campaign gets its own receipt
+    let id2 = client.mint(&campaign2, &donor, &1_000_000);
+    assert_ne!(id, id2);
+    assert_eq!(client.balance(&donor), 2);
+    assert_eq!(client.get_receipt_id(&campaign2, &donor), Some(id2));
+}
+
+#[test]
+#[should_panic(expected = "Receipt not found")]
+fn test_burn_receipt() {
+    let env = Env::default();
+    let contract_id = env.register(ReceiptContract, ());
+    let client = ReceiptContractClient::new(&env, &contract_id);
+
+    let campaign = Address::generate(&env);
+    let donor = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let id = client.mint(&campaign, &donor, &10_000_000);
+    client.burn(&campaign, &donor);
+
+    assert_eq!(client.balance(&donor), 0);
+    assert_eq!(client.get_receipt_id(&campaign, &donor), None);
+
+    // Burned receipt no longer exists
+    client.owner_of(&id);
+}
@@ -1,11 +1,49 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, symbol_short};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, token::TokenInterface, Address, Env, MuxedAddress, String, symbol_short};
 
 // Storage keys
-const BALANCE: soroban_sdk::Symbol = symbol_short!("BALANCE");
 const NAME: soroban_sdk::Symbol = symbol_short!("NAME");
 const SYMBOL: soroban_sdk::Symbol = symbol_short!("SYMBOL");
 const TOTAL: soroban_sdk::Symbol = symbol_short!("TOTAL");
+const ADMIN: soroban_sdk::Symbol = symbol_short!("ADMIN");
+const MAX_SUPPLY: soroban_sdk::Symbol = symbol_short!("MAX");
+const DECIMALS: soroban_sdk::Symbol = symbol_short!("DECIMALS");
+const ICON_URI: soroban_sdk::Symbol = symbol_short!("ICON");
+const DESCRIPTION: soroban_sdk::Symbol = symbol_short!("DESC");
+const CLAWBACK: soroban_sdk::Symbol = symbol_short!("CLAWBACK");
+
+// Emission schedule state
+const EMISSION: soroban_sdk::Symbol = symbol_short!("EMISSION");
+const EMISSION_START: soroban_sdk::Symbol = symbol_short!("EM_START");
+const EMITTED_PERIODS: soroban_sdk::Symbol = symbol_short!("EM_PERIOD");
+
+// Max token decimals
+const MAX_DECIMALS: u32 = 18;
+
+// Persistent storage keys (one entry per holder)
+// Allowances live in temporary storage until their expiration ledger
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Balance(Address),
+    Allowance(Address, Address),
+    Authorized(Address),
+    Checkpoint(Address, u32),
+    CheckpointCount(Address),
+    SupplyCheckpoint(u32),
+    SupplyCheckpointCount,
+    Delegate(Address),
+    VoteCheckpoint(Address, u32),
+    VoteCheckpointCount(Address),
+}
+
+// Value (balance atau total supply) as of the end of `ledger`
+#[contracttype]
+#[derive(Clone)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub value: i128,
+}
 
 // Contract struct
 #[contract]
@@ -18,34 +56,184 @@ pub struct TokenInfo {
     pub name: String,
     pub symbol: String,
     pub total_supply: i128,
+    pub decimals: u32,
+    pub icon_uri: Option<String>,
+    pub description: Option<String>,
+    pub clawback_enabled: bool,
+}
+
+// Token parameters passed to initialize
+// clawback_enabled = admin boleh clawback token dari holder (tidak bisa diubah setelah initialize)
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenParams {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub clawback_enabled: bool,
+}
+
+// Emission schedule: `amount_per_period` di-mint ke treasury setiap `period` detik
+#[contracttype]
+#[derive(Clone)]
+pub struct EmissionSchedule {
+    pub treasury: Address,
+    pub amount_per_period: i128,
+    pub period: u64,
+}
+
+// Allowance amount with the ledger it expires at
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// SEP-41 events
+#[contractevent(data_format = "vec")]
+#[derive(Clone)]
+pub struct Approve {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: Option<u64>,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct Mint {
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct SetAdmin {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct SetAuthorized {
+    #[topic]
+    pub id: Address,
+    pub authorize: bool,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct Clawback {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct DelegateChanged {
+    #[topic]
+    pub delegator: Address,
+    pub from_delegate: Address,
+    pub to_delegate: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct MetadataUpdated {
+    #[topic]
+    pub admin: Address,
+    pub icon_uri: Option<String>,
+    pub description: Option<String>,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
 }
 
 #[contractimpl]
 impl TokenContract {
 
-    // Initialize token dengan nama, symbol, dan supply
+    // Initialize token dengan nama, symbol, decimals, dan supply
+    // max_supply = batas supply permanen (None = tanpa batas)
+    // emission = jadwal emission ke treasury, mulai dari waktu initialize
     pub fn initialize(
         env: Env,
         admin: Address,
-        name: String,
-        symbol: String,
+        params: TokenParams,
         total_supply: i128,
+        max_supply: Option<i128>,
+        emission: Option<EmissionSchedule>,
     ) {
         // Verify admin authorization
         admin.require_auth();
 
+        if env.storage().instance().has(&ADMIN) {
+            panic!("Token sudah di-initialize");
+        }
+
+        let TokenParams {
+            name,
+            symbol,
+            decimals,
+            clawback_enabled,
+        } = params;
+
         // Validasi input
+        if decimals > MAX_DECIMALS {
+            panic!("Decimals maksimal 18");
+        }
+
         if total_supply <= 0 {
             panic!("Total supply harus lebih dari 0");
         }
 
+        if let Some(max_supply) = max_supply {
+            if max_supply < total_supply {
+                panic!("Max supply lebih kecil dari total supply");
+            }
+            env.storage().instance().set(&MAX_SUPPLY, &max_supply);
+        }
+
+        if let Some(emission) = emission {
+            if emission.amount_per_period <= 0 || emission.period == 0 {
+                panic!("Emission amount dan period harus lebih dari 0");
+            }
+            env.storage().instance().set(&EMISSION, &emission);
+            env.storage().instance().set(&EMISSION_START, &env.ledger().timestamp());
+            env.storage().instance().set(&EMITTED_PERIODS, &0u64);
+        }
+
         // Simpan token info
         env.storage().instance().set(&NAME, &name);
         env.storage().instance().set(&SYMBOL, &symbol);
-        env.storage().instance().set(&TOTAL, &total_supply);
+        env.storage().instance().set(&DECIMALS, &decimals);
+        env.storage().instance().set(&CLAWBACK, &clawback_enabled);
+        Self::write_total_supply(&env, total_supply);
+        env.storage().instance().set(&ADMIN, &admin);
 
         // Set balance admin = total supply
-        env.storage().instance().set(&BALANCE, &total_supply);
+        Self::write_balance(&env, admin, total_supply);
     }
 
     // Get nama token
@@ -63,13 +251,464 @@ impl TokenContract {
         env.storage().instance().get(&TOTAL).unwrap()
     }
 
-    // Get balance
-    pub fn get_balance(env: Env) -> i128 {
-        env.storage().instance().get(&BALANCE).unwrap_or(0)
+    // Get semua token info (untuk wallet display)
+    pub fn get_token_info(env: Env) -> TokenInfo {
+        TokenInfo {
+            name: Self::get_name(env.clone()),
+            symbol: Self::get_symbol(env.clone()),
+            total_supply: Self::get_total_supply(env.clone()),
+            decimals: env.storage().instance().get(&DECIMALS).unwrap(),
+            icon_uri: env.storage().instance().get(&ICON_URI),
+            description: env.storage().instance().get(&DESCRIPTION),
+            clawback_enabled: env.storage().instance().get(&CLAWBACK).unwrap_or(false),
+        }
+    }
+
+    // Update icon URI dan description - Admin only
+    // None menghapus field tersebut
+    pub fn set_metadata(env: Env, icon_uri: Option<String>, description: Option<String>) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        match &icon_uri {
+            Some(icon_uri) => env.storage().instance().set(&ICON_URI, icon_uri),
+            None => env.storage().instance().remove(&ICON_URI),
+        }
+        match &description {
+            Some(description) => env.storage().instance().set(&DESCRIPTION, description),
+            None => env.storage().instance().remove(&DESCRIPTION),
+        }
+
+        MetadataUpdated {
+            admin,
+            icon_uri,
+            description,
+        }
+        .publish(&env);
+    }
+
+    // Get balance `id` pada akhir ledger tertentu (snapshot untuk voting)
+    pub fn balance_at(env: Env, id: Address, ledger: u32) -> i128 {
+        let count_key = DataKey::CheckpointCount(id.clone());
+        Self::read_checkpoint(&env, count_key, |idx| DataKey::Checkpoint(id.clone(), idx), ledger)
+    }
+
+    // Get total supply pada akhir ledger tertentu
+    pub fn total_supply_at(env: Env, ledger: u32) -> i128 {
+        Self::read_checkpoint(&env, DataKey::SupplyCheckpointCount, DataKey::SupplyCheckpoint, ledger)
+    }
+
+    // Delegate voting power dari delegator ke delegatee (token tidak berpindah)
+    pub fn delegate(env: Env, delegator: Address, delegatee: Address) {
+        delegator.require_auth();
+
+        let from_delegate = Self::get_delegate(env.clone(), delegator.clone());
+        if from_delegate == delegatee {
+            return;
+        }
+
+        env.storage().persistent().set(&DataKey::Delegate(delegator.clone()), &delegatee);
+
+        let balance = Self::read_balance(&env, delegator.clone());
+        Self::move_votes(&env, from_delegate.clone(), -balance);
+        Self::move_votes(&env, delegatee.clone(), balance);
+
+        DelegateChanged {
+            delegator,
+            from_delegate,
+            to_delegate: delegatee,
+        }
+        .publish(&env);
+    }
+
+    // Get delegate dari account (default: account itu sendiri)
+    pub fn get_delegate(env: Env, account: Address) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegate(account.clone()))
+            .unwrap_or(account)
+    }
+
+    // Get voting power account saat ini
+    pub fn get_votes(env: Env, account: Address) -> i128 {
+        Self::get_past_votes(env, account, u32::MAX)
+    }
+
+    // Get voting power account pada akhir ledger tertentu
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+        let count_key = DataKey::VoteCheckpointCount(account.clone());
+        Self::read_checkpoint(&env, count_key, |idx| DataKey::VoteCheckpoint(account.clone(), idx), ledger)
+    }
+
+    // Get admin
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN).unwrap()
+    }
+
+    // Mint token baru ke `to` - Admin only
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
+        }
+
+        Self::mint_supply(&env, to, amount);
+    }
+
+    // Burn token dari `from` - Admin only
+    // SEP-41 `burn` sudah dipakai untuk burn oleh holder sendiri, jadi admin burn pakai nama terpisah
+    pub fn admin_burn(env: Env, from: Address, amount: i128) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
+        }
+
+        Self::burn_supply(&env, from, amount);
+    }
+
+    // Get max supply (None = tanpa batas)
+    pub fn get_max_supply(env: Env) -> Option<i128> {
+        env.storage().instance().get(&MAX_SUPPLY)
+    }
+
+    // Get emission schedule
+    pub fn get_emission(env: Env) -> Option<EmissionSchedule> {
+        env.storage().instance().get(&EMISSION)
+    }
+
+    // Get jumlah token emission yang bisa di-claim sekarang
+    pub fn get_claimable_emission(env: Env) -> i128 {
+        let (_, amount) = Self::due_emission(&env);
+        amount
+    }
+
+    // Mint semua emission period yang sudah lewat ke treasury
+    // Bisa dipanggil siapa saja, returns jumlah yang di-mint
+    pub fn claim_emission(env: Env) -> i128 {
+        let emission = match Self::get_emission(env.clone()) {
+            Some(emission) => emission,
+            None => panic!("Emission schedule tidak ada"),
+        };
+
+        let (periods, amount) = Self::due_emission(&env);
+        if amount == 0 {
+            panic!("Tidak ada emission yang bisa di-claim");
+        }
+
+        let emitted: u64 = env.storage().instance().get(&EMITTED_PERIODS).unwrap();
+        env.storage().instance().set(&EMITTED_PERIODS, &(emitted + periods));
+
+        Self::mint_supply(&env, emission.treasury, amount);
+        amount
+    }
+
+    // Ganti admin - Admin only
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&ADMIN, &new_admin);
+
+        SetAdmin { admin, new_admin }.publish(&env);
+    }
+
+    // Freeze (false) atau unfreeze (true) account - Admin only
+    // Account yang dibekukan tidak bisa kirim atau terima token
+    pub fn set_authorized(env: Env, id: Address, authorize: bool) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        let key = DataKey::Authorized(id.clone());
+        if authorize {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &false);
+        }
+
+        SetAuthorized { id, authorize }.publish(&env);
+    }
+
+    // Check apakah account boleh transfer (default true)
+    pub fn authorized(env: Env, id: Address) -> bool {
+        env.storage().persistent().get(&DataKey::Authorized(id)).unwrap_or(true)
+    }
+
+    // Tarik kembali token dari account (juga yang dibekukan) - Admin only
+    // Token yang di-clawback di-burn dari supply
+    // Hanya bisa kalau clawback diaktifkan saat initialize
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        if !env.storage().instance().get(&CLAWBACK).unwrap_or(false) {
+            panic!("Clawback tidak diaktifkan");
+        }
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
+        }
+
+        Self::debit_balance(&env, from.clone(), amount);
+
+        let total: i128 = env.storage().instance().get(&TOTAL).unwrap();
+        Self::write_total_supply(&env, total - amount);
+
+        Clawback { from, amount }.publish(&env);
+    }
+}
+
+// Internal balance & allowance helpers
+impl TokenContract {
+
+    // Credit `to` and grow total supply, max supply tidak boleh dilewati
+    fn mint_supply(env: &Env, to: Address, amount: i128) {
+        let total: i128 = env.storage().instance().get(&TOTAL).unwrap();
+        let new_total = match total.checked_add(amount) {
+            Some(new_total) => new_total,
+            None => panic!("Melebihi max supply"),
+        };
+
+        if let Some(max_supply) = Self::get_max_supply(env.clone()) {
+            if new_total > max_supply {
+                panic!("Melebihi max supply");
+            }
+        }
+
+        Self::receive_balance(env, to.clone(), amount);
+        Self::write_total_supply(env, new_total);
+
+        Mint { to, amount }.publish(env);
+    }
+
+    // Periods elapsed but not yet emitted, and the amount they mint (capped by max supply)
+    fn due_emission(env: &Env) -> (u64, i128) {
+        let emission = match Self::get_emission(env.clone()) {
+            Some(emission) => emission,
+            None => return (0, 0),
+        };
+
+        let start: u64 = env.storage().instance().get(&EMISSION_START).unwrap();
+        let emitted: u64 = env.storage().instance().get(&EMITTED_PERIODS).unwrap();
+        let elapsed = (env.ledger().timestamp() - start) / emission.period;
+        let periods = elapsed - emitted;
+        let amount = emission.amount_per_period.checked_mul(periods as i128);
+
+        // Amount yang overflow tetap dibatasi max supply, tanpa max supply tidak bisa di-mint
+        let amount = match (amount, Self::get_max_supply(env.clone())) {
+            (amount, Some(max_supply)) => {
+                let total: i128 = env.storage().instance().get(&TOTAL).unwrap();
+                amount.unwrap_or(i128::MAX).min(max_supply - total)
+            }
+            (Some(amount), None) => amount,
+            (None, None) => panic!("Emission melebihi batas i128"),
+        };
+
+        (periods, amount)
+    }
+
+    fn check_nonnegative_amount(amount: i128) {
+        if amount < 0 {
+            panic!("Amount tidak boleh negatif");
+        }
+    }
+
+    fn read_allowance(env: &Env, from: Address, spender: Address) -> AllowanceValue {
+        let key = DataKey::Allowance(from, spender);
+        match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+            // Expired allowance dianggap 0
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            Some(allowance) => AllowanceValue {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            },
+            None => AllowanceValue {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+
+    fn write_allowance(env: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            panic!("Expiration ledger sudah lewat");
+        }
+
+        let key = DataKey::Allowance(from, spender);
+        let allowance = AllowanceValue {
+            amount,
+            expiration_ledger,
+        };
+        env.storage().temporary().set(&key, &allowance);
+
+        // Keep entry alive until it expires
+        if amount > 0 {
+            let live_for = expiration_ledger - env.ledger().sequence();
+            env.storage().temporary().extend_ttl(&key, live_for, live_for);
+        }
+    }
+
+    fn spend_allowance(env: &Env, from: Address, spender: Address, amount: i128) {
+        let allowance = Self::read_allowance(env, from.clone(), spender.clone());
+        if allowance.amount < amount {
+            panic!("Allowance tidak cukup");
+        }
+
+        if amount > 0 {
+            Self::write_allowance(env, from, spender, allowance.amount - amount, allowance.expiration_ledger);
+        }
+    }
+
+    fn check_authorized(env: &Env, id: &Address) {
+        if !Self::authorized(env.clone(), id.clone()) {
+            panic!("Account dibekukan");
+        }
+    }
+
+    fn spend_balance(env: &Env, from: Address, amount: i128) {
+        Self::check_authorized(env, &from);
+        Self::debit_balance(env, from, amount);
+    }
+
+    fn receive_balance(env: &Env, to: Address, amount: i128) {
+        Self::check_authorized(env, &to);
+        let balance = Self::read_balance(env, to.clone());
+        Self::write_balance(env, to, balance + amount);
+    }
+
+    // Debit without the frozen check (used directly by clawback)
+    fn debit_balance(env: &Env, from: Address, amount: i128) {
+        let balance = Self::read_balance(env, from.clone());
+        if balance < amount {
+            panic!("Balance tidak cukup");
+        }
+        Self::write_balance(env, from, balance - amount);
+    }
+
+    fn write_balance(env: &Env, id: Address, balance: i128) {
+        let previous = Self::read_balance(env, id.clone());
+        env.storage().persistent().set(&DataKey::Balance(id.clone()), &balance);
+        let count_key = DataKey::CheckpointCount(id.clone());
+        Self::write_checkpoint(env, count_key, |idx| DataKey::Checkpoint(id.clone(), idx), balance);
+
+        // Balance change moves the holder's delegate votes
+        let delegate = Self::get_delegate(env.clone(), id);
+        Self::move_votes(env, delegate, balance - previous);
+    }
+
+    fn move_votes(env: &Env, delegate: Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+
+        let votes = Self::get_votes(env.clone(), delegate.clone());
+        let count_key = DataKey::VoteCheckpointCount(delegate.clone());
+        Self::write_checkpoint(env, count_key, |idx| DataKey::VoteCheckpoint(delegate.clone(), idx), votes + delta);
+    }
+
+    fn write_total_supply(env: &Env, total: i128) {
+        env.storage().instance().set(&TOTAL, &total);
+        Self::write_checkpoint(env, DataKey::SupplyCheckpointCount, DataKey::SupplyCheckpoint, total);
     }
 
-    // Transfer token (simplified - real token contract lebih kompleks)
-    pub fn transfer(env: Env, from: Address, _to: Address, amount: i128) {
+    // Record value for the current ledger as entry `count` of the series
+    // (overwrites the last entry if already written this ledger)
+    fn write_checkpoint(env: &Env, count_key: DataKey, entry_key: impl Fn(u32) -> DataKey, value: i128) {
+        let ledger = env.ledger().sequence();
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+        if count > 0 {
+            let last: Checkpoint = env.storage().persistent().get(&entry_key(count - 1)).unwrap();
+            if last.ledger == ledger {
+                env.storage().persistent().set(&entry_key(count - 1), &Checkpoint { ledger, value });
+                return;
+            }
+        }
+        env.storage().persistent().set(&entry_key(count), &Checkpoint { ledger, value });
+        env.storage().persistent().set(&count_key, &(count + 1));
+    }
+
+    // Binary search over the indexed entries for the last checkpoint at or before `ledger`,
+    // 0 jika belum ada
+    fn read_checkpoint(env: &Env, count_key: DataKey, entry_key: impl Fn(u32) -> DataKey, ledger: u32) -> i128 {
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        if count == 0 {
+            return 0;
+        }
+
+        // Latest value is the common case
+        let last: Checkpoint = env.storage().persistent().get(&entry_key(count - 1)).unwrap();
+        if last.ledger <= ledger {
+            return last.value;
+        }
+
+        let mut low = 0;
+        let mut high = count - 1;
+        while low < high {
+            let mid = (low + high) / 2;
+            let checkpoint: Checkpoint = env.storage().persistent().get(&entry_key(mid)).unwrap();
+            if checkpoint.ledger <= ledger {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            0
+        } else {
+            let checkpoint: Checkpoint = env.storage().persistent().get(&entry_key(low - 1)).unwrap();
+            checkpoint.value
+        }
+    }
+
+    fn read_balance(env: &Env, id: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::Balance(id)).unwrap_or(0)
+    }
+
+    fn burn_supply(env: &Env, from: Address, amount: i128) {
+        Self::spend_balance(env, from.clone(), amount);
+
+        let total: i128 = env.storage().instance().get(&TOTAL).unwrap();
+        Self::write_total_supply(env, total - amount);
+
+        Burn { from, amount }.publish(env);
+    }
+}
+
+// SEP-41 token interface, so TokenContract works with token::Client
+#[contractimpl]
+impl TokenInterface for TokenContract {
+
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Self::read_allowance(&env, from, spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+
+        Self::check_nonnegative_amount(amount);
+
+        Self::write_allowance(&env, from.clone(), spender.clone(), amount, expiration_ledger);
+
+        Approve {
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&env);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        Self::read_balance(&env, id)
+    }
+
+    // Transfer token dari `from` ke `to`
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
         // Verify authorization
         from.require_auth();
 
@@ -78,18 +717,71 @@ impl TokenContract {
             panic!("Amount harus lebih dari 0");
         }
 
-        // Get current balances (simplified)
-        let balance: i128 = env.storage().instance().get(&BALANCE).unwrap_or(0);
+        // Debit sender, credit recipient
+        Self::spend_balance(&env, from.clone(), amount);
+        Self::receive_balance(&env, to.address(), amount);
 
-        // Check sufficient balance
-        if balance < amount {
-            panic!("Balance tidak cukup");
+        Transfer {
+            from,
+            to: to.address(),
+            to_muxed_id: to.id(),
+            amount,
+        }
+        .publish(&env);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
+        }
+
+        Self::spend_allowance(&env, from.clone(), spender, amount);
+        Self::spend_balance(&env, from.clone(), amount);
+        Self::receive_balance(&env, to.clone(), amount);
+
+        Transfer {
+            from,
+            to,
+            to_muxed_id: None,
+            amount,
+        }
+        .publish(&env);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
+        }
+
+        Self::burn_supply(&env, from, amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount harus lebih dari 0");
         }
 
-        // Update balance (simplified version)
-        let new_balance = balance - amount;
-        env.storage().instance().set(&BALANCE, &new_balance);
+        Self::spend_allowance(&env, from.clone(), spender, amount);
+        Self::burn_supply(&env, from, amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&DECIMALS).unwrap()
+    }
+
+    fn name(env: Env) -> String {
+        Self::get_name(env)
+    }
+
+    fn symbol(env: Env) -> String {
+        Self::get_symbol(env)
     }
 }
 
-mod test;
\ No newline at end of file
+mod test;
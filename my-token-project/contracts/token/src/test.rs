@@ -1,7 +1,17 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, testutils::Events, testutils::Ledger, vec, Address, Env, IntoVal, String, Symbol};
+
+// Helper function to build token parameters
+fn token_params(name: &String, symbol: &String, decimals: u32) -> TokenParams {
+    TokenParams {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        decimals,
+        clawback_enabled: false,
+    }
+}
 
 #[test]
 fn test_initialize_token() {
@@ -21,13 +31,13 @@ fn test_initialize_token() {
     let symbol = String::from_str(&env, "IDR");
     let supply = 1_000_000_000i128; // 1 miliar
 
-    client.initialize(&admin, &name, &symbol, &supply);
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
 
     // Verify token info
     assert_eq!(client.get_name(), name);
     assert_eq!(client.get_symbol(), symbol);
     assert_eq!(client.get_total_supply(), supply);
-    assert_eq!(client.get_balance(), supply);
+    assert_eq!(client.balance(&admin), supply);
 }
 
 #[test]
@@ -43,7 +53,7 @@ fn test_get_token_info() {
     let symbol = String::from_str(&env, "WST");
     let supply = 5_000_000i128;
 
-    client.initialize(&admin, &name, &symbol, &supply);
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
 
     // Test getter functions
     assert_eq!(client.get_name(), name);
@@ -66,7 +76,7 @@ fn test_initialize_invalid_supply() {
     let supply = 0i128; // Invalid!
 
     // Should panic
-    client.initialize(&admin, &name, &symbol, &supply);
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
 }
 
 #[test]
@@ -84,12 +94,828 @@ fn test_transfer() {
     let symbol = String::from_str(&env, "TST");
     let supply = 1_000_000i128;
 
-    client.initialize(&admin, &name, &symbol, &supply);
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
 
     // Transfer tokens
     let transfer_amount = 100_000i128;
     client.transfer(&admin, &user, &transfer_amount);
 
-    // Balance should be reduced
-    assert_eq!(client.get_balance(), supply - transfer_amount);
-}
\ No newline at end of file
+    // Sender debited, recipient credited
+    assert_eq!(client.balance(&admin), supply - transfer_amount);
+    assert_eq!(client.balance(&user), transfer_amount);
+}
+
+#[test]
+#[should_panic(expected = "Balance tidak cukup")]
+fn test_transfer_cannot_spend_other_balance() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Test Token");
+    let symbol = String::from_str(&env, "TST");
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &None, &None);
+
+    // Attacker has no balance of their own
+    client.transfer(&attacker, &user, &100i128);
+}
+
+#[test]
+fn test_transfer_conserves_supply() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut holders = vec![&env, admin.clone()];
+    for _ in 0..4 {
+        holders.push_back(Address::generate(&env));
+    }
+
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Test Token");
+    let symbol = String::from_str(&env, "TST");
+    let supply = 1_000_000i128;
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
+
+    // Many transfers between holders (deterministic pseudo-random)
+    let mut seed: u64 = 42;
+    for _ in 0..100 {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let from = holders.get((seed >> 33) as u32 % holders.len()).unwrap();
+        let to = holders.get((seed >> 13) as u32 % holders.len()).unwrap();
+
+        let balance = client.balance(&from);
+        if balance == 0 {
+            continue;
+        }
+        let amount = (seed >> 40) as i128 % balance + 1;
+        client.transfer(&from, &to, &amount);
+
+        // Sum of balances always equals total supply
+        let total: i128 = holders.iter().map(|holder| client.balance(&holder)).sum();
+        assert_eq!(total, supply);
+    }
+}
+// Helper function to deploy and initialize a token, returns client
+fn setup_token<'a>(env: &Env, admin: &Address, supply: i128) -> TokenContractClient<'a> {
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let name = String::from_str(env, "Test Token");
+    let symbol = String::from_str(env, "TST");
+    client.initialize(admin, &token_params(&name, &symbol, 7), &supply, &None, &None);
+    client
+}
+
+#[test]
+fn test_sep41_metadata() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    assert_eq!(client.name(), String::from_str(&env, "Test Token"));
+    assert_eq!(client.symbol(), String::from_str(&env, "TST"));
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    client.approve(&admin, &spender, &300_000, &100);
+    assert_eq!(client.allowance(&admin, &spender), 300_000);
+
+    // Spender moves funds, allowance reduced
+    client.transfer_from(&spender, &admin, &user, &100_000);
+    assert_eq!(client.allowance(&admin, &spender), 200_000);
+    assert_eq!(client.balance(&admin), 900_000);
+    assert_eq!(client.balance(&user), 100_000);
+    assert_eq!(client.balance(&spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "Allowance tidak cukup")]
+fn test_transfer_from_exceeds_allowance() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    client.approve(&admin, &spender, &100_000, &100);
+    client.transfer_from(&spender, &admin, &user, &100_001);
+}
+
+#[test]
+fn test_allowance_expires() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    let expiration_ledger = env.ledger().sequence() + 10;
+    client.approve(&admin, &spender, &100_000, &expiration_ledger);
+
+    // Still valid at the expiration ledger
+    env.ledger().with_mut(|li| {
+        li.sequence_number = expiration_ledger;
+    });
+    assert_eq!(client.allowance(&admin, &spender), 100_000);
+
+    // Expired afterwards
+    env.ledger().with_mut(|li| {
+        li.sequence_number = expiration_ledger + 1;
+    });
+    assert_eq!(client.allowance(&admin, &spender), 0);
+    assert!(client.try_transfer_from(&spender, &admin, &user, &1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Expiration ledger sudah lewat")]
+fn test_approve_past_expiration() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+    });
+    client.approve(&admin, &spender, &100_000, &99);
+}
+
+#[test]
+fn test_burn_and_burn_from() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    client.burn(&admin, &100_000);
+    assert_eq!(client.balance(&admin), 900_000);
+    assert_eq!(client.get_total_supply(), 900_000);
+
+    client.approve(&admin, &spender, &50_000, &100);
+    client.burn_from(&spender, &admin, &50_000);
+    assert_eq!(client.balance(&admin), 850_000);
+    assert_eq!(client.get_total_supply(), 850_000);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+fn test_admin_mint_updates_supply() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    assert_eq!(client.get_admin(), admin);
+
+    client.mint(&user, &250_000);
+
+    // Mint event: topics ["mint", to], data amount
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint"), &user).into_val(&env),
+                250_000i128.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance(&user), 250_000);
+    assert_eq!(client.get_total_supply(), 1_250_000);
+
+    // Holder burn reduces supply again
+    client.burn(&user, &50_000);
+    assert_eq!(client.get_total_supply(), 1_200_000);
+}
+
+#[test]
+fn test_mint_requires_admin_auth() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.mint(&user, &1);
+
+    // Admin signed the mint
+    assert_eq!(env.auths().first().unwrap().0, admin);
+
+    // Without any auth mint fails
+    env.set_auths(&[]);
+    assert!(client.try_mint(&user, &1).is_err());
+}
+
+#[test]
+fn test_admin_burn() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.transfer(&admin, &user, &100_000);
+
+    client.admin_burn(&user, &30_000);
+
+    // Burn event: topics ["burn", from], data amount
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "burn"), &user).into_val(&env),
+                30_000i128.into_val(&env),
+            ),
+        ]
+    );
+
+    // Admin signed the burn, not the holder
+    assert_eq!(env.auths().first().unwrap().0, admin);
+    assert_eq!(client.balance(&user), 70_000);
+    assert_eq!(client.get_total_supply(), 970_000);
+
+    // Without any auth admin burn fails
+    env.set_auths(&[]);
+    assert!(client.try_admin_burn(&user, &1).is_err());
+}
+
+#[test]
+fn test_set_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    client.set_admin(&new_admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "set_admin"), &admin).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_admin(), new_admin);
+
+    // New admin authorizes mints
+    client.mint(&user, &100);
+    assert_eq!(env.auths().first().unwrap().0, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Token sudah di-initialize")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    let name = String::from_str(&env, "Again");
+    let symbol = String::from_str(&env, "AGN");
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &None, &None);
+}
+
+#[test]
+#[should_panic(expected = "Melebihi max supply")]
+fn test_mint_beyond_max_supply() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Capped Token");
+    let symbol = String::from_str(&env, "CAP");
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &Some(1_500_000i128), &None);
+    assert_eq!(client.get_max_supply(), Some(1_500_000));
+
+    // Up to the cap is fine
+    client.mint(&user, &500_000);
+    assert_eq!(client.get_total_supply(), 1_500_000);
+
+    client.mint(&user, &1);
+}
+
+#[test]
+fn test_claim_emission_per_period() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Community Token");
+    let symbol = String::from_str(&env, "COM");
+    let emission = EmissionSchedule {
+        treasury: treasury.clone(),
+        amount_per_period: 100_000,
+        period: 1000,
+    };
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &Some(1_250_000i128), &Some(emission));
+
+    // Nothing due before the first period elapses
+    assert_eq!(client.get_claimable_emission(), 0);
+    assert!(client.try_claim_emission().is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1000;
+    });
+
+    // Anyone can claim, no auth needed
+    env.set_auths(&[]);
+    assert_eq!(client.claim_emission(), 100_000);
+    assert_eq!(client.balance(&treasury), 100_000);
+
+    // Same period cannot be claimed twice
+    assert!(client.try_claim_emission().is_err());
+
+    // Missed periods accumulate, capped at max supply
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3000;
+    });
+    assert_eq!(client.get_claimable_emission(), 150_000);
+    assert_eq!(client.claim_emission(), 150_000);
+    assert_eq!(client.get_total_supply(), 1_250_000);
+}
+
+#[test]
+fn test_emission_overflow_capped_by_max_supply() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Big Emission");
+    let symbol = String::from_str(&env, "BIG");
+    let emission = EmissionSchedule {
+        treasury: treasury.clone(),
+        amount_per_period: i128::MAX / 2,
+        period: 1,
+    };
+    let max_supply = i128::MAX - 1_000;
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &Some(max_supply), &Some(emission));
+
+    // Three periods overflow i128, the claim is capped at max supply instead
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3;
+    });
+    assert_eq!(client.get_claimable_emission(), max_supply - 1_000_000);
+    assert_eq!(client.claim_emission(), max_supply - 1_000_000);
+    assert_eq!(client.get_total_supply(), max_supply);
+}
+
+#[test]
+#[should_panic(expected = "Emission melebihi batas i128")]
+fn test_emission_overflow_without_max_supply() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Big Emission");
+    let symbol = String::from_str(&env, "BIG");
+    let emission = EmissionSchedule {
+        treasury,
+        amount_per_period: i128::MAX / 2,
+        period: 1,
+    };
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &None, &Some(emission));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3;
+    });
+    client.claim_emission();
+}
+
+#[test]
+#[should_panic(expected = "Max supply lebih kecil dari total supply")]
+fn test_initialize_max_supply_below_total() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Bad Cap");
+    let symbol = String::from_str(&env, "BAD");
+    client.initialize(&admin, &token_params(&name, &symbol, 7), &1_000_000i128, &Some(999_999i128), &None);
+}
+
+#[test]
+fn test_token_info_and_metadata_update() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Workshop Token");
+    let symbol = String::from_str(&env, "WST");
+    client.initialize(&admin, &token_params(&name, &symbol, 6), &1_000_000i128, &None, &None);
+
+    let info = client.get_token_info();
+    assert_eq!(info.name, name);
+    assert_eq!(info.symbol, symbol);
+    assert_eq!(info.total_supply, 1_000_000);
+    assert_eq!(info.decimals, 6);
+    assert_eq!(info.icon_uri, None);
+    assert_eq!(client.decimals(), 6);
+
+    // Admin sets metadata
+    let icon_uri = String::from_str(&env, "https://example.com/wst.png");
+    let description = String::from_str(&env, "Token untuk workshop Stellar");
+    client.set_metadata(&Some(icon_uri.clone()), &Some(description.clone()));
+    assert_eq!(env.auths().first().unwrap().0, admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "metadata_updated"), &admin).into_val(&env),
+                soroban_sdk::map![
+                    &env,
+                    (Symbol::new(&env, "description"), description.to_val()),
+                    (Symbol::new(&env, "icon_uri"), icon_uri.to_val()),
+                ]
+                .into_val(&env),
+            ),
+        ]
+    );
+
+    let info = client.get_token_info();
+    assert_eq!(info.icon_uri, Some(icon_uri));
+    assert_eq!(info.description, Some(description));
+
+    // Clearing a field
+    client.set_metadata(&None, &info.description);
+    assert_eq!(client.get_token_info().icon_uri, None);
+
+    // Metadata update requires admin auth
+    env.set_auths(&[]);
+    assert!(client.try_set_metadata(&None, &None).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Decimals maksimal 18")]
+fn test_initialize_too_many_decimals() {
+    let env = Env::default();
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let name = String::from_str(&env, "Bad Decimals");
+    let symbol = String::from_str(&env, "BAD");
+    client.initialize(&admin, &token_params(&name, &symbol, 19), &1_000_000i128, &None, &None);
+}
+
+#[test]
+fn test_freeze_blocks_transfers() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.transfer(&admin, &user, &100_000);
+
+    assert!(client.authorized(&user));
+    client.set_authorized(&user, &false);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "set_authorized"), &user).into_val(&env),
+                false.into_val(&env),
+            ),
+        ]
+    );
+    assert!(!client.authorized(&user));
+
+    // Frozen account can neither send nor receive
+    assert!(client.try_transfer(&user, &other, &1).is_err());
+    assert!(client.try_transfer(&admin, &user, &1).is_err());
+    assert!(client.try_burn(&user, &1).is_err());
+
+    // Unfreeze restores transfers
+    client.set_authorized(&user, &true);
+    client.transfer(&user, &other, &40_000);
+    assert_eq!(client.balance(&other), 40_000);
+}
+
+#[test]
+fn test_clawback() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let contract_id = env.register(TokenContract, ());
+    let client = TokenContractClient::new(&env, &contract_id);
+    let params = TokenParams {
+        name: String::from_str(&env, "Regulated Token"),
+        symbol: String::from_str(&env, "REG"),
+        decimals: 7,
+        clawback_enabled: true,
+    };
+    client.initialize(&admin, &params, &1_000_000i128, &None, &None);
+    assert!(client.get_token_info().clawback_enabled);
+    client.transfer(&admin, &user, &100_000);
+
+    // Clawback works on frozen accounts too
+    client.set_authorized(&user, &false);
+    client.clawback(&user, &60_000);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "clawback"), &user).into_val(&env),
+                60_000i128.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance(&user), 40_000);
+    assert_eq!(client.get_total_supply(), 940_000);
+
+    // Admin only
+    env.set_auths(&[]);
+    assert!(client.try_clawback(&user, &1).is_err());
+    assert!(client.try_set_authorized(&user, &true).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Clawback tidak diaktifkan")]
+fn test_clawback_disabled() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.transfer(&admin, &user, &100_000);
+    assert!(!client.get_token_info().clawback_enabled);
+
+    client.clawback(&user, &60_000);
+}
+
+#[test]
+fn test_balance_and_supply_checkpoints() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+    });
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 20;
+    });
+    client.transfer(&admin, &user, &100_000);
+    // Second change in the same ledger overwrites the checkpoint
+    client.transfer(&admin, &user, &50_000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 30;
+    });
+    client.mint(&user, &200_000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 40;
+    });
+    client.burn(&user, &50_000);
+
+    // Before any balance
+    assert_eq!(client.balance_at(&user, &5), 0);
+    assert_eq!(client.total_supply_at(&5), 0);
+
+    // Snapshots between and at checkpoints
+    assert_eq!(client.balance_at(&admin, &10), 1_000_000);
+    assert_eq!(client.balance_at(&admin, &19), 1_000_000);
+    assert_eq!(client.balance_at(&admin, &20), 850_000);
+    assert_eq!(client.balance_at(&user, &25), 150_000);
+    assert_eq!(client.balance_at(&user, &30), 350_000);
+    assert_eq!(client.balance_at(&user, &100), 300_000);
+
+    assert_eq!(client.total_supply_at(&29), 1_000_000);
+    assert_eq!(client.total_supply_at(&30), 1_200_000);
+    assert_eq!(client.total_supply_at(&40), 1_150_000);
+    assert_eq!(client.total_supply_at(&40), client.get_total_supply());
+}
+
+#[test]
+fn test_checkpoints_indexed_per_ledger() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1;
+    });
+    let client = setup_token(&env, &admin, 1_000_000i128);
+
+    // One transfer per ledger, plus repeated transfers within a ledger
+    for ledger in 2..=20u32 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number = ledger * 10;
+        });
+        client.transfer(&admin, &user, &1_000);
+        client.transfer(&admin, &user, &1_000);
+    }
+
+    // One entry per ledger with a change, not per transfer
+    env.as_contract(&client.address, || {
+        let count: u32 = env.storage().persistent().get(&DataKey::CheckpointCount(user.clone())).unwrap();
+        assert_eq!(count, 19);
+        let last: Checkpoint = env.storage().persistent().get(&DataKey::Checkpoint(user.clone(), 18)).unwrap();
+        assert_eq!(last.ledger, 200);
+        assert_eq!(last.value, 38_000);
+
+        let supply_count: u32 = env.storage().persistent().get(&DataKey::SupplyCheckpointCount).unwrap();
+        assert_eq!(supply_count, 1);
+    });
+
+    // Binary search across the indexed entries
+    for ledger in 2..=20u32 {
+        let expected = (ledger as i128 - 1) * 2_000;
+        assert_eq!(client.balance_at(&user, &(ledger * 10)), expected);
+        assert_eq!(client.balance_at(&user, &(ledger * 10 + 9)), expected);
+        assert_eq!(client.balance_at(&admin, &(ledger * 10 - 1)), 1_000_000 - expected + 2_000);
+    }
+    assert_eq!(client.balance_at(&user, &19), 0);
+    assert_eq!(client.total_supply_at(&1_000), 1_000_000);
+}
+
+#[test]
+fn test_delegate_votes() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+    });
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.transfer(&admin, &alice, &300_000);
+
+    // Holders vote with their own balance by default
+    assert_eq!(client.get_delegate(&alice), alice);
+    assert_eq!(client.get_votes(&alice), 300_000);
+    assert_eq!(client.get_votes(&admin), 700_000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 20;
+    });
+    client.delegate(&alice, &bob);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "delegate_changed"), &alice).into_val(&env),
+                soroban_sdk::map![
+                    &env,
+                    (Symbol::new(&env, "from_delegate"), alice.to_val()),
+                    (Symbol::new(&env, "to_delegate"), bob.to_val()),
+                ]
+                .into_val(&env),
+            ),
+        ]
+    );
+
+    // Voting power moved without moving tokens
+    assert_eq!(client.balance(&alice), 300_000);
+    assert_eq!(client.get_votes(&alice), 0);
+    assert_eq!(client.get_votes(&bob), 300_000);
+
+    // Later balance changes follow the delegate
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 30;
+    });
+    client.transfer(&admin, &alice, &100_000);
+    assert_eq!(client.get_votes(&bob), 400_000);
+
+    // Past votes from checkpoints
+    assert_eq!(client.get_past_votes(&alice, &15), 300_000);
+    assert_eq!(client.get_past_votes(&bob, &15), 0);
+    assert_eq!(client.get_past_votes(&bob, &25), 300_000);
+    assert_eq!(client.get_past_votes(&bob, &30), 400_000);
+
+    // Delegating back to self restores own votes
+    client.delegate(&alice, &alice);
+    assert_eq!(client.get_votes(&alice), 400_000);
+    assert_eq!(client.get_votes(&bob), 0);
+}
+
+#[test]
+fn test_vote_checkpoints_indexed_per_ledger() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1;
+    });
+    let client = setup_token(&env, &admin, 1_000_000i128);
+    client.transfer(&admin, &alice, &100_000);
+
+    // Alice moves her votes to bob and back within each ledger, ending on bob on even ledgers
+    for ledger in 2..=12u32 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number = ledger * 10;
+        });
+        client.delegate(&alice, &bob);
+        client.delegate(&alice, &alice);
+        if ledger % 2 == 0 {
+            client.delegate(&alice, &bob);
+        }
+    }
+
+    // One entry per ledger with a change, not per delegation
+    env.as_contract(&client.address, || {
+        let count: u32 = env.storage().persistent().get(&DataKey::VoteCheckpointCount(bob.clone())).unwrap();
+        assert_eq!(count, 11);
+        let last: Checkpoint = env.storage().persistent().get(&DataKey::VoteCheckpoint(bob.clone(), 10)).unwrap();
+        assert_eq!(last.ledger, 120);
+        assert_eq!(last.value, 100_000);
+    });
+
+    // Binary search across the indexed entries
+    assert_eq!(client.get_past_votes(&bob, &19), 0);
+    for ledger in 2..=12u32 {
+        let expected = if ledger % 2 == 0 { 100_000 } else { 0 };
+        assert_eq!(client.get_past_votes(&bob, &(ledger * 10 + 5)), expected);
+        assert_eq!(client.get_past_votes(&alice, &(ledger * 10 + 5)), 100_000 - expected);
+    }
+    assert_eq!(client.get_votes(&bob), 100_000);
+    assert_eq!(client.get_votes(&alice), 0);
+}
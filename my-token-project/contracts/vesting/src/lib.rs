@@ -0,0 +1,187 @@
+#![no_std]
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address, Env, Symbol};
+
+// Storage keys
+const CONFIG: Symbol = symbol_short!("config");
+const RELEASED: Symbol = symbol_short!("released");
+const REVOKED: Symbol = symbol_short!("revoked");
+
+// Vesting schedule
+// cliff = detik setelah start sebelum token pertama vest
+// duration = detik dari start sampai semua token vest (linear)
+// revocable = admin bisa revoke token yang belum vest
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingConfig {
+    pub admin: Address,
+    pub token: Address,
+    pub beneficiary: Address,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+}
+
+// Typed contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+}
+
+// Holds tokens for a beneficiary, fund by transferring tokens to this contract
+#[contract]
+pub struct VestingContract;
+
+#[contractimpl]
+impl VestingContract {
+
+    /// Initialize vesting schedule
+    pub fn initialize(env: Env, config: VestingConfig) {
+        config.admin.require_auth();
+
+        if env.storage().instance().has(&CONFIG) {
+            panic!("Vesting already initialized");
+        }
+
+        if config.duration == 0 {
+            panic!("Duration must be greater than 0");
+        }
+
+        if config.cliff > config.duration {
+            panic!("Cliff cannot exceed duration");
+        }
+
+        if config.start.checked_add(config.cliff).is_none() {
+            panic_with_error!(&env, Error::Overflow);
+        }
+
+        env.storage().instance().set(&CONFIG, &config);
+        env.storage().instance().set(&RELEASED, &0i128);
+        env.storage().instance().set(&REVOKED, &false);
+    }
+
+    /// Get amount vested but not yet released
+    pub fn releasable(env: Env) -> i128 {
+        Self::vested_amount(&env) - Self::get_released(env)
+    }
+
+    /// Transfer releasable tokens to beneficiary (bisa dipanggil siapa saja)
+    /// Returns amount released
+    pub fn release(env: Env) -> i128 {
+        let config = Self::get_config(env.clone());
+
+        let amount = Self::releasable(env.clone());
+        if amount <= 0 {
+            panic!("No tokens to release");
+        }
+
+        let released = Self::get_released(env.clone());
+        env.storage().instance().set(&RELEASED, &(released + amount));
+
+        token::Client::new(&env, &config.token).transfer(
+            &env.current_contract_address(),
+            &config.beneficiary,
+            &amount,
+        );
+
+        amount
+    }
+
+    /// Revoke unvested tokens back to admin - Admin only, jika revocable
+    /// Tokens already vested stay releasable by the beneficiary
+    /// Returns amount returned to admin
+    pub fn revoke(env: Env) -> i128 {
+        let config = Self::get_config(env.clone());
+        config.admin.require_auth();
+
+        if !config.revocable {
+            panic!("Vesting is not revocable");
+        }
+
+        if Self::is_revoked(env.clone()) {
+            panic!("Vesting already revoked");
+        }
+
+        let unvested = Self::total_allocation(&env) - Self::vested_amount(&env);
+        env.storage().instance().set(&REVOKED, &true);
+
+        if unvested > 0 {
+            token::Client::new(&env, &config.token).transfer(
+                &env.current_contract_address(),
+                &config.admin,
+                &unvested,
+            );
+        }
+
+        unvested
+    }
+
+    /// Get vesting schedule
+    pub fn get_config(env: Env) -> VestingConfig {
+        match env.storage().instance().get(&CONFIG) {
+            Some(config) => config,
+            None => panic!("Vesting not initialized"),
+        }
+    }
+
+    /// Get total amount already released to beneficiary
+    pub fn get_released(env: Env) -> i128 {
+        env.storage().instance().get(&RELEASED).unwrap_or(0)
+    }
+
+    /// Check apakah vesting sudah di-revoke
+    pub fn is_revoked(env: Env) -> bool {
+        env.storage().instance().get(&REVOKED).unwrap_or(false)
+    }
+}
+
+impl VestingContract {
+
+    // Tokens ever held for beneficiary: current balance + already released
+    fn total_allocation(env: &Env) -> i128 {
+        let config = Self::get_config(env.clone());
+        let balance = token::Client::new(env, &config.token).balance(&env.current_contract_address());
+        balance + Self::get_released(env.clone())
+    }
+
+    // Linear vesting from start, nothing before the cliff
+    // After revoke the remaining balance is exactly what had vested
+    fn vested_amount(env: &Env) -> i128 {
+        let config = Self::get_config(env.clone());
+        let total = Self::total_allocation(env);
+
+        if Self::is_revoked(env.clone()) {
+            return total;
+        }
+
+        let now = env.ledger().timestamp();
+        if now < config.start + config.cliff {
+            return 0;
+        }
+
+        let elapsed = now - config.start;
+        if elapsed >= config.duration {
+            return total;
+        }
+
+        Self::mul_div(env, total, elapsed as i128, config.duration as i128)
+    }
+
+    // value * num / den, split into whole and remainder parts so large values don't overflow
+    fn mul_div(env: &Env, value: i128, num: i128, den: i128) -> i128 {
+        let whole = (value / den).checked_mul(num);
+        let part = (value % den).checked_mul(num).map(|r| r / den);
+        match (whole, part) {
+            (Some(whole), Some(part)) => match whole.checked_add(part) {
+                Some(result) => result,
+                None => panic_with_error!(env, Error::Overflow),
+            },
+            _ => panic_with_error!(env, Error::Overflow),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
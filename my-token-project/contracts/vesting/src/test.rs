@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+// Helper function to issue the team token, whole supply starts in the treasury
+fn team_token<'a>(env: &Env, treasury: &Address, supply: i128) -> token::Client<'a> {
+    let params = ::token::TokenParams {
+        name: String::from_str(env, "Team Token"),
+        symbol: String::from_str(env, "TEAM"),
+        decimals: 7,
+        clawback_enabled: false,
+    };
+    let token_address = env.register(::token::TokenContract, ());
+    ::token::TokenContractClient::new(env, &token_address).initialize(treasury, &params, &supply, &None, &None);
+    token::Client::new(env, &token_address)
+}
+
+// Helper function to deploy a funded vesting wallet
+// Schedule: start now, cliff 100s, duration 1000s, 1_000_000 tokens
+fn setup_vesting<'a>(
+    env: &Env,
+    admin: &Address,
+    beneficiary: &Address,
+    revocable: bool,
+) -> (VestingContractClient<'a>, token::Client<'a>) {
+    let token = team_token(env, admin, 10_000_000);
+
+    let contract_id = env.register(VestingContract, ());
+    let client = VestingContractClient::new(env, &contract_id);
+
+    client.initialize(&VestingConfig {
+        admin: admin.clone(),
+        token: token.address.clone(),
+        beneficiary: beneficiary.clone(),
+        start: env.ledger().timestamp(),
+        cliff: 100,
+        duration: 1000,
+        revocable,
+    });
+    token.transfer(admin, &contract_id, &1_000_000);
+
+    (client, token)
+}
+
+#[test]
+fn test_release_after_cliff() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (client, token) = setup_vesting(&env, &admin, &beneficiary, false);
+
+    // Nothing before the cliff
+    env.ledger().with_mut(|li| {
+        li.timestamp += 99;
+    });
+    assert_eq!(client.releasable(), 0);
+    assert!(client.try_release().is_err());
+
+    // Linear after the cliff (cliff counts from start)
+    env.ledger().with_mut(|li| {
+        li.timestamp += 151;
+    });
+    assert_eq!(client.releasable(), 250_000);
+    assert_eq!(client.release(), 250_000);
+    assert_eq!(token.balance(&beneficiary), 250_000);
+    assert_eq!(client.releasable(), 0);
+
+    // Everything after duration
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2000;
+    });
+    assert_eq!(client.release(), 750_000);
+    assert_eq!(client.get_released(), 1_000_000);
+    assert_eq!(token.balance(&beneficiary), 1_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_revoke_returns_unvested() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (client, token) = setup_vesting(&env, &admin, &beneficiary, true);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 400;
+    });
+
+    // 40% vested stays with beneficiary, rest goes back to admin
+    assert_eq!(client.revoke(), 600_000);
+    assert!(client.is_revoked());
+    assert_eq!(token.balance(&admin), 9_600_000);
+
+    // Vesting frozen at revoke time
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2000;
+    });
+    assert_eq!(client.releasable(), 400_000);
+    assert_eq!(client.release(), 400_000);
+    assert_eq!(token.balance(&beneficiary), 400_000);
+}
+
+#[test]
+#[should_panic(expected = "Vesting is not revocable")]
+fn test_revoke_not_revocable() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (client, _) = setup_vesting(&env, &admin, &beneficiary, false);
+    client.revoke();
+}
+
+#[test]
+#[should_panic(expected = "Cliff cannot exceed duration")]
+fn test_initialize_cliff_after_duration() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let token = team_token(&env, &admin, 10_000_000);
+    let contract_id = env.register(VestingContract, ());
+    let client = VestingContractClient::new(&env, &contract_id);
+
+    client.initialize(&VestingConfig {
+        admin,
+        token: token.address,
+        beneficiary,
+        start: 0,
+        cliff: 1001,
+        duration: 1000,
+        revocable: true,
+    });
+}
+
+#[test]
+fn test_vested_amount_large_allocation() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let allocation = i128::MAX / 100;
+    let token = team_token(&env, &admin, allocation);
+    let contract_id = env.register(VestingContract, ());
+    let client = VestingContractClient::new(&env, &contract_id);
+
+    client.initialize(&VestingConfig {
+        admin: admin.clone(),
+        token: token.address.clone(),
+        beneficiary,
+        start: env.ledger().timestamp(),
+        cliff: 0,
+        duration: 1000,
+        revocable: false,
+    });
+    token.transfer(&admin, &contract_id, &allocation);
+
+    // allocation * 500s would overflow i128, half of it has vested
+    env.ledger().with_mut(|li| {
+        li.timestamp += 500;
+    });
+    assert_eq!(client.releasable(), allocation / 2);
+
+    // Fully vested needs no multiplication
+    env.ledger().with_mut(|li| {
+        li.timestamp += 500;
+    });
+    assert_eq!(client.releasable(), allocation);
+}
+
+#[test]
+fn test_initialize_cliff_overflow() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let token = team_token(&env, &admin, 1_000_000);
+    let contract_id = env.register(VestingContract, ());
+    let client = VestingContractClient::new(&env, &contract_id);
+
+    let result = client.try_initialize(&VestingConfig {
+        admin,
+        token: token.address.clone(),
+        beneficiary,
+        start: u64::MAX - 10,
+        cliff: 100,
+        duration: 1000,
+        revocable: false,
+    });
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+}